@@ -1,6 +0,0 @@
-for i in 01 02 03 04 05 06 07 08 09 10 11 12
-do
-
-cargo run --release --bin day$i < inputs/day$i.txt
-
-done
\ No newline at end of file
@@ -0,0 +1,243 @@
+use std::{collections::BTreeMap, io::ErrorKind, path::Path};
+
+use crate::runner::{DayResult, Part};
+
+#[cfg(test)]
+use crate::runner::PartResult;
+
+/// 64 bit FNV-1a hash of the input, so that answers of different inputs for the same day can be
+/// told apart without storing the inputs themselves.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// The part was run, but gave no answer.
+    Missing {
+        expected: String,
+    },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, answer: &str) -> Self {
+        match expected {
+            None => Self::Unknown,
+            Some(expected) if expected == answer => Self::Correct,
+            Some(expected) => Self::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    /// Empty if the part gave no answer.
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Check {
+    /// Checks the `part` of `result` against the `expected` answer. `None` if there is neither an
+    /// answer nor an expected one.
+    pub fn new(result: &DayResult, part: Part, expected: Option<&str>) -> Option<Self> {
+        let (answer, verdict) = match (result.part(part), expected) {
+            (Some(part_result), expected) => (
+                part_result.answer.clone(),
+                Verdict::new(expected, &part_result.answer),
+            ),
+            (None, Some(expected)) => (
+                String::new(),
+                Verdict::Missing {
+                    expected: expected.to_string(),
+                },
+            ),
+            (None, None) => return None,
+        };
+
+        Some(Self {
+            day: result.day,
+            part,
+            answer,
+            verdict,
+        })
+    }
+}
+
+/// The parts that are run if `selected` is the selected part, see `Config::part`.
+pub fn selected_parts(selected: Option<Part>) -> impl Iterator<Item = Part> {
+    [Part::One, Part::Two]
+        .into_iter()
+        .filter(move |part| selected.is_none_or(|selected| selected == *part))
+}
+
+/// Known correct answers, stored one per line as `day part input-hash answer`. Empty lines and
+/// lines starting with `#` are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, Part, u64), String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = || format!("line {}: expected `day part hash answer`", idx + 1);
+
+            let mut fields = line.split_whitespace();
+            let day = fields
+                .next()
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(error)?;
+            let part = match fields.next() {
+                Some("1") => Part::One,
+                Some("2") => Part::Two,
+                _ => return Err(error()),
+            };
+            let hash = fields
+                .next()
+                .and_then(|h| u64::from_str_radix(h, 16).ok())
+                .ok_or_else(error)?;
+            let answer = fields.next().ok_or_else(error)?;
+
+            answers
+                .entries
+                .insert((day, part, hash), answer.to_string());
+        }
+
+        Ok(answers)
+    }
+
+    /// A missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("cannot read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_text())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# day part input-hash answer\n");
+        for ((day, part, hash), answer) in &self.entries {
+            let part = part.number();
+            text.push_str(&format!("{day} {part} {hash:016x} {answer}\n"));
+        }
+        text
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, day: u8, part: Part, hash: u64) -> Option<&str> {
+        self.entries.get(&(day, part, hash)).map(String::as_str)
+    }
+
+    /// Stores the answers of all solved parts that have a solution, replacing previously recorded
+    /// ones.
+    pub fn record(&mut self, result: &DayResult, hash: u64) {
+        for (part, part_result) in result.parts().filter(|(_, p)| p.has_solution) {
+            self.entries
+                .insert((result.day, part, hash), part_result.answer.clone());
+        }
+    }
+
+    /// Checks the answers of the `selected` parts, a part with a recorded answer that gave none is
+    /// `Verdict::Missing`.
+    pub fn check(&self, result: &DayResult, hash: u64, selected: Option<Part>) -> Vec<Check> {
+        selected_parts(selected)
+            .filter_map(|part| Check::new(result, part, self.get(result.day, part, hash)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+fn example_result(part1: &str, part2: Option<&str>) -> DayResult {
+    let part = |answer: &str| PartResult {
+        answer: answer.to_string(),
+        has_solution: true,
+        time: std::time::Duration::ZERO,
+        allocations: None,
+    };
+
+    DayResult {
+        day: 1,
+        parse_time: std::time::Duration::ZERO,
+        parse_allocations: None,
+        part1: Some(part(part1)),
+        part2: part2.map(part),
+    }
+}
+
+#[test]
+fn test_record_and_check() {
+    let hash = input_hash("L68\nL30\n");
+    assert_ne!(hash, input_hash("L68\nL31\n"));
+
+    let mut answers = Answers::default();
+    answers.record(&example_result("3", None), hash);
+
+    let answers = Answers::parse(&answers.to_text()).unwrap();
+    assert_eq!(answers.len(), 1);
+    assert_eq!(answers.get(1, Part::One, hash), Some("3"));
+
+    let verdicts: Vec<_> = answers
+        .check(&example_result("4", Some("6")), hash, None)
+        .into_iter()
+        .map(|check| check.verdict)
+        .collect();
+    assert_eq!(
+        verdicts,
+        vec![
+            Verdict::Wrong {
+                expected: "3".to_string()
+            },
+            Verdict::Unknown
+        ]
+    );
+
+    assert_eq!(
+        answers.check(&example_result("3", None), hash, None)[0].verdict,
+        Verdict::Correct
+    );
+
+    // a part with a recorded answer that stops giving one is a regression
+    let mut result = example_result("3", None);
+    result.part1 = None;
+    let checks = answers.check(&result, hash, None);
+    assert_eq!(checks.len(), 1);
+    assert_eq!(
+        checks[0].verdict,
+        Verdict::Missing {
+            expected: "3".to_string()
+        }
+    );
+    assert!(answers.check(&result, hash, Some(Part::Two)).is_empty());
+
+    assert!(Answers::parse("1 3 00ff 42").is_err());
+}
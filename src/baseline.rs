@@ -0,0 +1,154 @@
+use std::{collections::BTreeMap, io::ErrorKind, path::Path, time::Duration};
+
+/// Phases that got slower by less than this are never reported, short phases are too noisy.
+const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub phase: String,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change against the baseline, `0.1` is 10% slower.
+    pub fn change(&self) -> f64 {
+        self.current.as_nanos() as f64 / self.baseline.as_nanos().max(1) as f64 - 1.0
+    }
+
+    /// `threshold` is the allowed relative slowdown, `0.1` allows phases to get 10% slower.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.current > self.baseline + NOISE_FLOOR && self.change() > threshold
+    }
+}
+
+/// Reference durations of every phase, stored one per line as `day phase input-hash nanoseconds`.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    entries: BTreeMap<(u8, String, u64), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut baseline = Self::default();
+
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = || format!("line {}: expected `day phase hash nanoseconds`", idx + 1);
+
+            let mut fields = line.split_whitespace();
+            let day = fields
+                .next()
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(error)?;
+            let phase = fields.next().ok_or_else(error)?;
+            let hash = fields
+                .next()
+                .and_then(|h| u64::from_str_radix(h, 16).ok())
+                .ok_or_else(error)?;
+            let nanos = fields
+                .next()
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(error)?;
+
+            baseline
+                .entries
+                .insert((day, phase.to_string(), hash), Duration::from_nanos(nanos));
+        }
+
+        Ok(baseline)
+    }
+
+    /// A missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("cannot read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_text())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# day phase input-hash nanoseconds\n");
+        for ((day, phase, hash), duration) in &self.entries {
+            text.push_str(&format!(
+                "{day} {phase} {hash:016x} {}\n",
+                duration.as_nanos()
+            ));
+        }
+        text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Stores the durations of the given phases, replacing previous ones.
+    pub fn update(&mut self, day: u8, hash: u64, phases: &[(impl AsRef<str>, Duration)]) {
+        for (phase, duration) in phases {
+            self.entries
+                .insert((day, phase.as_ref().to_string(), hash), *duration);
+        }
+    }
+
+    /// Compares all phases that have a baseline.
+    pub fn compare(
+        &self,
+        day: u8,
+        hash: u64,
+        phases: &[(impl AsRef<str>, Duration)],
+    ) -> Vec<Comparison> {
+        phases
+            .iter()
+            .filter_map(|(phase, current)| {
+                let phase = phase.as_ref().to_string();
+                let baseline = self.entries.get(&(day, phase.clone(), hash))?;
+                Some(Comparison {
+                    day,
+                    phase,
+                    baseline: *baseline,
+                    current: *current,
+                })
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_compare() {
+    let ms = Duration::from_millis;
+
+    let mut baseline = Baseline::default();
+    baseline.update(8, 42, &[("parse", ms(10)), ("part1", ms(100))]);
+    let baseline = Baseline::parse(&baseline.to_text()).unwrap();
+
+    let comparisons = baseline.compare(
+        8,
+        42,
+        &[("parse", ms(12)), ("part1", ms(105)), ("part2", ms(1))],
+    );
+    assert_eq!(comparisons.len(), 2);
+    assert!(comparisons[0].is_regression(0.1));
+    assert!(!comparisons[1].is_regression(0.1));
+    assert!((comparisons[0].change() - 0.2).abs() < 1e-9);
+
+    assert!(baseline.compare(8, 43, &[("parse", ms(12))]).is_empty());
+
+    let tiny = Comparison {
+        day: 1,
+        phase: "part1".to_string(),
+        baseline: Duration::from_nanos(100),
+        current: Duration::from_nanos(500),
+    };
+    assert!(!tiny.is_regression(0.1));
+}
@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use crate::{
+    ParseError,
+    runner::{Config, Solver},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        // nearest rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        let nanos: Vec<_> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            p95,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+
+impl DayBench {
+    /// The statistics of all phases that were run, named like in `DayResult::phases`.
+    pub fn phases(&self) -> Vec<(&'static str, &Stats)> {
+        let mut phases = vec![("parse", &self.parse)];
+        phases.extend(self.part1.as_ref().map(|s| ("part1", s)));
+        phases.extend(self.part2.as_ref().map(|s| ("part2", s)));
+        phases.push(("total", &self.total));
+        phases
+    }
+}
+
+/// Solves the input `warmup` times without measuring, then `runs` times, collecting the timings of
+/// every phase. `runs` must be at least one, an invalid input fails on the first run.
+pub fn bench(
+    solve: Solver,
+    input: &str,
+    config: &Config,
+    warmup: usize,
+    runs: usize,
+) -> Result<DayBench, ParseError> {
+    assert!(runs > 0, "at least one run is needed");
+
+    for _ in 0..warmup {
+        solve(input, config)?;
+    }
+
+    let mut day = 0;
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    let mut total = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let result = solve(input, config)?;
+        day = result.day;
+        parse.push(result.parse_time);
+        total.push(result.total_time());
+        part1.extend(result.part1.map(|p| p.time));
+        part2.extend(result.part2.map(|p| p.time));
+    }
+
+    Ok(DayBench {
+        day,
+        runs,
+        parse: Stats::from_samples(&parse).expect("runs is not zero"),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+        total: Stats::from_samples(&total).expect("runs is not zero"),
+    })
+}
+
+#[test]
+fn test_stats() {
+    let samples: Vec<_> = [5, 1, 4, 2, 3, 100]
+        .into_iter()
+        .map(Duration::from_nanos)
+        .collect();
+
+    let stats = Stats::from_samples(&samples).unwrap();
+    assert_eq!(stats.min, Duration::from_nanos(1));
+    assert_eq!(stats.median, Duration::from_nanos(3));
+    assert_eq!(stats.p95, Duration::from_nanos(100));
+    assert_eq!(stats.mean, Duration::from_nanos(19));
+    assert_eq!(stats.stddev, Duration::from_nanos(36));
+
+    let stats = Stats::from_samples(&[Duration::from_nanos(7)]).unwrap();
+    assert_eq!(stats.median, Duration::from_nanos(7));
+    assert_eq!(stats.stddev, Duration::ZERO);
+
+    assert_eq!(Stats::from_samples(&[]), None);
+}
@@ -0,0 +1,508 @@
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+use advent_of_code_2025::{
+    answers::{Answers, Verdict, input_hash},
+    baseline::Baseline,
+    bench::bench,
+    fixtures::{Example, examples, fixtures_dir},
+    parallel::{available_threads, speedup},
+    report::{Format, render, render_bench},
+    runner::{Config, DAYS, Part, input_path, solver},
+};
+
+const USAGE: &str = "usage: aoc [DAYS...] [--part 1|2] [--inputs DIR] [--format table|json|csv]
+           [--bench RUNS] [--warmup RUNS] [--answers FILE] [--record] [--example]
+           [--baseline FILE] [--save-baseline] [--check-perf] [--threshold PERCENT] [--parallel]
+
+DAYS are single days (3) or inclusive ranges (1-12), all days if omitted.
+Inputs are read from DIR/dayNN.txt, DIR defaults to `inputs`.
+The json and csv formats report durations in nanoseconds.
+With --bench every day is solved RUNS times after --warmup (default 3) unmeasured runs and the
+min, median, p95, mean and standard deviation of every phase are reported.
+Answers are checked against the known answers in FILE (default `answers.txt`), keyed by day, part
+and a hash of the input. --record stores the current answers in FILE instead.
+With --example the example fixtures are solved and checked instead of the puzzle inputs.
+Phase durations are compared with the baseline in FILE (default `baseline.txt`), phases that got
+slower by more than --threshold (default 10) percent are reported and fail the run with
+--check-perf. --save-baseline stores the current durations instead. With --bench the medians are
+compared and stored, which is much less noisy than single runs. Runs with --parallel and the
+totals of runs with --part have their own baseline entries.
+When built with the `count-allocations` feature, the allocations, allocated bytes and peak live
+bytes of every phase are reported as well.
+With --parallel the independent items of an input are split across all cores. Every day is also
+solved (or benchmarked) sequentially and the speedup of the total duration is reported.";
+
+struct Options {
+    days: Vec<u8>,
+    part: Option<Part>,
+    inputs: PathBuf,
+    format: Format,
+    bench_runs: Option<usize>,
+    warmup_runs: usize,
+    answers: PathBuf,
+    record: bool,
+    example: bool,
+    baseline: PathBuf,
+    save_baseline: bool,
+    check_perf: bool,
+    threshold: f64,
+    parallel: bool,
+}
+
+fn parse_days(arg: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |s: &str| -> Result<u8, String> {
+        match s.trim().parse() {
+            Ok(day) if (1..=DAYS.len() as u8).contains(&day) => Ok(day),
+            _ => Err(format!("invalid day `{s}`, expected 1 to {}", DAYS.len())),
+        }
+    };
+
+    match arg.split_once('-') {
+        Some((start, end)) => {
+            let (start, end) = (parse_day(start)?, parse_day(end)?);
+            if start > end {
+                return Err(format!("invalid day range `{arg}`"));
+            }
+            Ok((start..=end).collect())
+        }
+        None => Ok(vec![parse_day(arg)?]),
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        days: Vec::new(),
+        part: None,
+        inputs: PathBuf::from("inputs"),
+        format: Format::Table,
+        bench_runs: None,
+        warmup_runs: 3,
+        answers: PathBuf::from("answers.txt"),
+        record: false,
+        example: false,
+        baseline: PathBuf::from("baseline.txt"),
+        save_baseline: false,
+        check_perf: false,
+        threshold: 0.1,
+        parallel: false,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                options.part = match args.next().as_deref() {
+                    Some("1") => Some(Part::One),
+                    Some("2") => Some(Part::Two),
+                    _ => return Err("--part expects 1 or 2".to_string()),
+                }
+            }
+            "--inputs" | "-i" => {
+                options.inputs = args.next().ok_or("--inputs expects a directory")?.into();
+            }
+            "--format" | "-f" => {
+                options.format = args
+                    .next()
+                    .as_deref()
+                    .and_then(Format::from_name)
+                    .ok_or("--format expects table, json or csv")?;
+            }
+            "--bench" | "-b" => {
+                options.bench_runs = match args.next().map(|n| n.parse()) {
+                    Some(Ok(runs)) if runs > 0 => Some(runs),
+                    _ => return Err("--bench expects a positive number of runs".to_string()),
+                }
+            }
+            "--warmup" => {
+                options.warmup_runs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .ok_or("--warmup expects a number of runs")?;
+            }
+            "--answers" | "-a" => {
+                options.answers = args.next().ok_or("--answers expects a file")?.into();
+            }
+            "--record" => options.record = true,
+            "--example" | "-e" => options.example = true,
+            "--baseline" => {
+                options.baseline = args.next().ok_or("--baseline expects a file")?.into();
+            }
+            "--save-baseline" => options.save_baseline = true,
+            "--check-perf" => options.check_perf = true,
+            "--threshold" => {
+                options.threshold = match args.next().map(|t| t.parse::<f64>()) {
+                    Some(Ok(percent)) if percent >= 0.0 => percent / 100.0,
+                    _ => return Err("--threshold expects a non-negative percentage".to_string()),
+                }
+            }
+            "--parallel" | "-j" => options.parallel = true,
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`\n{USAGE}")),
+            _ => options.days.extend(parse_days(&arg)?),
+        }
+    }
+
+    if options.record && (options.bench_runs.is_some() || options.example) {
+        return Err("--record cannot be combined with --bench or --example".to_string());
+    }
+
+    if options.save_baseline && options.check_perf {
+        return Err("--save-baseline cannot be combined with --check-perf".to_string());
+    }
+
+    if options.days.is_empty() {
+        options.days = (1..=DAYS.len() as u8).collect();
+    }
+    options.days.sort_unstable();
+    options.days.dedup();
+
+    Ok(options)
+}
+
+struct Job {
+    day: u8,
+    path: PathBuf,
+    config: Config,
+    example: Option<Example>,
+}
+
+fn jobs(options: &Options) -> Result<Vec<Job>, String> {
+    if !options.example {
+        return Ok(options
+            .days
+            .iter()
+            .map(|&day| Job {
+                day,
+                path: input_path(&options.inputs, day),
+                config: Config {
+                    part: options.part,
+                    example: false,
+                    parallel: options.parallel,
+                },
+                example: None,
+            })
+            .collect());
+    }
+
+    let mut jobs = Vec::new();
+
+    for example in examples(&fixtures_dir(), None)? {
+        if !options.days.contains(&example.day) {
+            continue;
+        }
+
+        let mut config = example.config();
+        if let Some(part) = options.part {
+            // some examples only work for one of the parts
+            if !example.expected.is_empty() && !example.expected.iter().any(|(p, _)| *p == part) {
+                continue;
+            }
+            config.part = Some(part);
+        }
+        config.parallel = options.parallel;
+
+        jobs.push(Job {
+            day: example.day,
+            path: example.path.clone(),
+            config,
+            example: Some(example),
+        });
+    }
+
+    Ok(jobs)
+}
+
+/// The name of a phase in the baseline. Durations of differently configured runs are not
+/// comparable, the total depends on the parts that were solved and every phase on `--parallel`.
+fn baseline_phase(phase: &str, config: &Config) -> String {
+    let mut name = phase.to_string();
+    if let (Some(part), "total") = (config.part, phase) {
+        name.push_str(&format!("@part{}", part.number()));
+    }
+    if config.parallel {
+        name.push_str("@parallel");
+    }
+    name
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut answers = match Answers::load(&options.answers) {
+        Ok(answers) => answers,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let jobs = match jobs(&options) {
+        Ok(jobs) => jobs,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut baseline = match Baseline::load(&options.baseline) {
+        Ok(baseline) => baseline,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut results = Vec::new();
+    let mut benches = Vec::new();
+    let mut checks = Vec::new();
+    let mut comparisons = Vec::new();
+    // day, sequential and parallel total duration
+    let mut speedups = Vec::new();
+    let mut failed = false;
+
+    for Job {
+        day,
+        path,
+        config,
+        example,
+    } in jobs
+    {
+        let input = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("day {day}: cannot read {}: {error}", path.display());
+                failed = true;
+                continue;
+            }
+        };
+
+        let hash = input_hash(&input);
+        let solve = solver(day).expect("days are validated");
+        let phases = match options.bench_runs {
+            Some(runs) => bench(solve, &input, &config, options.warmup_runs, runs).map(|result| {
+                let phases: Vec<(&str, Duration)> = result
+                    .phases()
+                    .into_iter()
+                    .map(|(phase, stats)| (phase, stats.median))
+                    .collect();
+                benches.push(result);
+                phases
+            }),
+            None => solve(&input, &config).map(|result| {
+                for (part, part_result) in result.parts() {
+                    if !part_result.has_solution {
+                        eprintln!(
+                            "day {day} part {}: no solution for {}",
+                            part.number(),
+                            path.display()
+                        );
+                        failed = true;
+                    }
+                }
+                if let Some(example) = &example {
+                    checks.extend(example.check(&result, config.part));
+                } else if options.record {
+                    answers.record(&result, hash);
+                } else {
+                    checks.extend(answers.check(&result, hash, config.part));
+                }
+                let phases = result.phases();
+                results.push(result);
+                phases
+            }),
+        };
+
+        if let (true, Ok(phases)) = (options.parallel, &phases) {
+            let config = Config {
+                parallel: false,
+                ..config
+            };
+            let sequential = match options.bench_runs {
+                Some(runs) => bench(solve, &input, &config, options.warmup_runs, runs)
+                    .map(|result| result.total.median),
+                None => solve(&input, &config).map(|result| result.total_time()),
+            };
+            let parallel = phases
+                .iter()
+                .find(|(phase, _)| *phase == "total")
+                .map(|(_, time)| *time)
+                .expect("every day has a total");
+            // it was already parsed successfully
+            speedups.extend(
+                sequential
+                    .ok()
+                    .map(|sequential| (day, sequential, parallel)),
+            );
+        }
+
+        let phases = phases.map(|phases| {
+            phases
+                .into_iter()
+                .map(|(phase, time)| (baseline_phase(phase, &config), time))
+                .collect::<Vec<_>>()
+        });
+        match phases {
+            Ok(phases) if options.save_baseline => baseline.update(day, hash, &phases),
+            Ok(phases) => comparisons.extend(baseline.compare(day, hash, &phases)),
+            Err(error) => {
+                eprint!("{}: {}", path.display(), error.diagnostic(&input));
+                failed = true;
+            }
+        }
+    }
+
+    if options.bench_runs.is_some() {
+        print!("{}", render_bench(&benches, options.format));
+    } else {
+        print!("{}", render(&results, options.format));
+    }
+
+    if !speedups.is_empty() {
+        for (day, sequential, parallel) in &speedups {
+            eprintln!(
+                "day {day}: {} µs sequential, {} µs parallel, {:.2}x speedup",
+                sequential.as_micros(),
+                parallel.as_micros(),
+                speedup(*sequential, *parallel)
+            );
+        }
+
+        let total = |f: fn(&(u8, Duration, Duration)) -> Duration| speedups.iter().map(f).sum();
+        eprintln!(
+            "parallel: {:.2}x speedup overall on {} threads",
+            speedup(total(|s| s.1), total(|s| s.2)),
+            available_threads()
+        );
+    }
+
+    if options.record {
+        if let Err(error) = answers.save(&options.answers) {
+            eprintln!("cannot write {}: {error}", options.answers.display());
+            return ExitCode::FAILURE;
+        }
+        eprintln!(
+            "recorded {} answers in {}",
+            results
+                .iter()
+                .map(|r| usize::from(r.part1.is_some()) + usize::from(r.part2.is_some()))
+                .sum::<usize>(),
+            options.answers.display()
+        );
+    } else if options.bench_runs.is_none() && (!answers.is_empty() || options.example) {
+        let count = |f: fn(&Verdict) -> bool| checks.iter().filter(|c| f(&c.verdict)).count();
+
+        for check in &checks {
+            match &check.verdict {
+                Verdict::Wrong { expected } => eprintln!(
+                    "day {} part {}: WRONG answer {}, expected {expected}",
+                    check.day,
+                    check.part.number(),
+                    check.answer
+                ),
+                Verdict::Missing { expected } => eprintln!(
+                    "day {} part {}: no answer, expected {expected}",
+                    check.day,
+                    check.part.number()
+                ),
+                Verdict::Correct | Verdict::Unknown => continue,
+            }
+            failed = true;
+        }
+
+        eprintln!(
+            "answers: {} correct, {} wrong, {} missing, {} unknown",
+            count(|v| *v == Verdict::Correct),
+            count(|v| matches!(v, Verdict::Wrong { .. })),
+            count(|v| matches!(v, Verdict::Missing { .. })),
+            count(|v| *v == Verdict::Unknown)
+        );
+    }
+
+    if options.save_baseline {
+        if let Err(error) = baseline.save(&options.baseline) {
+            eprintln!("cannot write {}: {error}", options.baseline.display());
+            return ExitCode::FAILURE;
+        }
+        eprintln!("saved baseline in {}", options.baseline.display());
+    } else if !comparisons.is_empty() {
+        let regressions: Vec<_> = comparisons
+            .iter()
+            .filter(|c| c.is_regression(options.threshold))
+            .collect();
+
+        for regression in &regressions {
+            eprintln!(
+                "day {} {}: SLOWER {} µs -> {} µs ({:+.0}%)",
+                regression.day,
+                regression.phase,
+                regression.baseline.as_micros(),
+                regression.current.as_micros(),
+                regression.change() * 100.0
+            );
+        }
+
+        eprintln!(
+            "perf: {} phases compared, {} slower than {:.0}% over the baseline",
+            comparisons.len(),
+            regressions.len(),
+            options.threshold * 100.0
+        );
+
+        if options.check_perf && !regressions.is_empty() {
+            failed = true;
+        }
+    } else if options.check_perf {
+        eprintln!(
+            "perf: no baseline for these inputs in {}",
+            options.baseline.display()
+        );
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(parse_days("3"), Ok(vec![3]));
+    assert_eq!(parse_days("1-4"), Ok(vec![1, 2, 3, 4]));
+    assert!(parse_days("0").is_err());
+    assert!(parse_days("13").is_err());
+    assert!(parse_days("5-2").is_err());
+
+    let options = parse_args(["8", "--part", "2", "1-2"].map(String::from)).unwrap();
+    assert_eq!(options.days, vec![1, 2, 8]);
+    assert_eq!(options.part, Some(Part::Two));
+    assert!(!options.parallel);
+    assert!(parse_args(["--parallel".to_string()]).unwrap().parallel);
+}
+
+#[test]
+fn test_baseline_phase() {
+    let config = Config::default();
+    assert_eq!(baseline_phase("total", &config), "total");
+
+    let config = Config {
+        part: Some(Part::One),
+        ..config
+    };
+    assert_eq!(baseline_phase("total", &config), "total@part1");
+    assert_eq!(baseline_phase("part1", &config), "part1");
+
+    let config = Config {
+        parallel: true,
+        ..config
+    };
+    assert_eq!(baseline_phase("total", &config), "total@part1@parallel");
+    assert_eq!(baseline_phase("parse", &config), "parse@parallel");
+}
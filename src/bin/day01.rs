@@ -1,88 +1,206 @@
-use std::{io::Read, time::Instant};
-
-fn parse_input(input: &str) -> Vec<i32> {
-    input
-        .lines()
-        .map(|line| {
-            let line = line.trim();
-            let factor = if line.starts_with("L") { -1 } else { 1 };
-            let line = line.trim_matches('L').trim_matches('R');
-            let amount: i32 = line.parse().expect("cannot parse input");
-            factor * amount
-        })
-        .collect()
+use std::{io::Read, path::PathBuf, process::ExitCode, time::Instant};
+
+use advent_of_code_2025::{
+    day01::{Day01, Dial, Histogram, parse_input, stream, trace, trace_csv},
+    runner::run_with,
+};
+
+/// The histogram has a line for every position of the dial.
+const MAX_HISTOGRAM_SIZE: i64 = 10_000_000;
+
+const HELP: &str = "\
+--size N is the number of dial positions (default 100), --start N where it starts (default 50).
+--target N is a position that is counted, it can be repeated (default 0). The examples are only
+solved with the default dial.
+--stream reads the rotations from stdin line by line in constant memory, for inputs of any size.
+--trace FILE writes every rotation with the positions it turned the dial from and to as CSV, and
+flags the ones that passed a target. --histogram FILE writes how often every position was landed
+on and passed over as CSV, for dials of up to 10000000 positions. FILE `-` is stdout, the input
+is PATH or stdin.";
+
+struct Options {
+    dial: Dial,
+    stream: bool,
+    trace: Option<PathBuf>,
+    histogram: Option<PathBuf>,
+    /// The arguments that are handled by `run_with`.
+    rest: Vec<String>,
 }
 
-fn dial(rotations: &[i32]) -> Vec<i32> {
-    let mut values = vec![50];
-
-    for &r in rotations {
-        values.push(
-            (values
-                .last()
-                .expect("at least one element exists in values")
-                + r)
-                .rem_euclid(100),
-        );
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let default = Dial::default();
+    let mut size = default.size;
+    let mut start = default.start;
+    let mut targets = Vec::new();
+    let mut stream = false;
+    let mut trace = None;
+    let mut histogram = None;
+    let mut rest = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut number = |what: &str| {
+            args.next()
+                .and_then(|n| n.parse().ok())
+                .ok_or(format!("{arg} expects {what}"))
+        };
+
+        match arg.as_str() {
+            "--size" => size = number("a number of positions")?,
+            "--start" => start = number("a position")?,
+            "--target" => targets.push(number("a position")?),
+            "--stream" => stream = true,
+            "--trace" => trace = Some(args.next().ok_or("--trace expects a file")?.into()),
+            "--histogram" => {
+                histogram = Some(args.next().ok_or("--histogram expects a file")?.into());
+            }
+            _ => rest.push(arg),
+        }
     }
 
-    values
-}
+    if stream && !rest.is_empty() {
+        return Err("--stream reads stdin and only takes the dial options".to_string());
+    }
 
-fn part1(input: &[i32]) -> usize {
-    dial(input).iter().filter(|&&n| n == 0).count()
-}
+    if (trace.is_some() || histogram.is_some())
+        && (rest.len() > 1 || rest.iter().any(|arg| arg.starts_with('-')))
+    {
+        return Err("--trace and --histogram take a single input PATH".to_string());
+    }
+
+    // the examples are checked against the answers for the default dial
+    let custom_dial = size != default.size || start != default.start || !targets.is_empty();
+    if custom_dial && rest.iter().any(|arg| arg == "--example") {
+        return Err("--example cannot be combined with --size, --start or --target".to_string());
+    }
 
-fn part2(input: &[i32]) -> usize {
-    let mut transformed_input = Vec::new();
+    if histogram.is_some() && size > MAX_HISTOGRAM_SIZE {
+        return Err(format!(
+            "--histogram takes a dial of at most {MAX_HISTOGRAM_SIZE} positions"
+        ));
+    }
 
-    for &n in input {
-        for _i in 0..n.abs() {
-            transformed_input.push(n.signum());
-        }
+    if targets.is_empty() {
+        targets = default.targets;
     }
 
-    part1(&transformed_input)
+    Ok(Options {
+        dial: Dial::new(size, start, targets)?,
+        stream,
+        trace,
+        histogram,
+        rest,
+    })
+}
+
+fn write(path: &PathBuf, contents: &str) -> Result<(), String> {
+    if path.as_os_str() == "-" {
+        print!("{contents}");
+        return Ok(());
+    }
+    std::fs::write(path, contents)
+        .map_err(|error| format!("error: cannot write {}: {error}\n", path.display()))
 }
 
-fn main() {
-    let mut input = String::new();
-    std::io::stdin()
-        .read_to_string(&mut input)
-        .expect("Reading input must work");
-
-    let before_parse = Instant::now();
-    let parsed_input = parse_input(&input);
-    let parse_time = before_parse.elapsed();
-
-    let before_part1 = Instant::now();
-    println!("Part 1: {}", part1(&parsed_input));
-    let part1_time = before_part1.elapsed();
-
-    let before_part2 = Instant::now();
-    println!("Part 2: {}", part2(&parsed_input));
-    let part2_time = before_part2.elapsed();
-
-    println!("==========================================");
-    println!("Parsing: {} µs", parse_time.as_micros());
-    println!("Part 1 : {} µs", part1_time.as_micros());
-    println!("Part 2 : {} µs", part2_time.as_micros());
-    println!(
-        "Total  : {} µs",
-        parse_time.as_micros() + part1_time.as_micros() + part2_time.as_micros()
+/// Writes the trace and histogram of the input.
+fn analyze(options: &Options) -> Result<(), String> {
+    let input = match options.rest.first() {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|error| format!("error: cannot read {path}: {error}\n"))?,
+        None => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|error| format!("error: cannot read input: {error}\n"))?;
+            input
+        }
+    };
+    let rotations = parse_input(&input).map_err(|error| error.diagnostic(&input))?;
+
+    let steps = trace(&rotations, &options.dial);
+    if let Some(path) = &options.trace {
+        write(path, &trace_csv(&steps))?;
+    }
+    if let Some(path) = &options.histogram {
+        write(path, &Histogram::new(&rotations, &options.dial).to_csv())?;
+    }
+
+    eprintln!(
+        "{} rotations, {} of them passed a target",
+        steps.len(),
+        steps.iter().filter(|step| step.passes > 0).count()
     );
+    Ok(())
 }
 
-#[test]
-fn test_part1() {
-    let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}\n{HELP}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.stream {
+        let before = Instant::now();
+        return match stream(std::io::stdin().lock(), options.dial) {
+            Ok(counter) => {
+                println!("Part 1: {}", counter.part1);
+                println!("Part 2: {}", counter.part2);
+                println!("==========================================");
+                println!(
+                    "Total  : {} µs for {} rotations",
+                    before.elapsed().as_micros(),
+                    counter.rotations
+                );
+                ExitCode::SUCCESS
+            }
+            Err(message) => {
+                eprint!("{message}");
+                ExitCode::FAILURE
+            }
+        };
+    }
 
-    assert_eq!(part1(&parse_input(input)), 3);
+    if options.trace.is_some() || options.histogram.is_some() {
+        return match analyze(&options) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(message) => {
+                eprint!("{message}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let dial = options.dial;
+    run_with::<Day01>(options.rest, HELP, &|input, _| {
+        Ok((parse_input(input)?, dial.clone()))
+    })
 }
 
 #[test]
-fn test_part2() {
-    let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+fn test_parse_args() {
+    let args = "--target 0 input.txt --size 40 --start 10 --target 25".split(' ');
+    let options = parse_args(args.map(String::from)).unwrap();
+    assert_eq!(options.dial, Dial::new(40, 10, vec![0, 25]).unwrap());
+    assert_eq!(options.rest, vec!["input.txt"]);
+    assert!(!options.stream);
+    assert!(parse_args(["--example", "--target", "25"].map(String::from)).is_err());
+    assert!(parse_args(["--size", "40", "--example"].map(String::from)).is_err());
+    assert_eq!(
+        parse_args(["--example".to_string()]).unwrap().rest,
+        ["--example"]
+    );
 
-    assert_eq!(part2(&parse_input(input)), 6);
+    assert_eq!(parse_args([]).unwrap().dial, Dial::default());
+    assert!(parse_args(["--stream".to_string()]).unwrap().stream);
+    assert!(parse_args(["--stream", "--example"].map(String::from)).is_err());
+    let options = parse_args(["--trace", "-", "input.txt"].map(String::from)).unwrap();
+    assert_eq!(options.trace, Some(PathBuf::from("-")));
+    assert!(parse_args(["--histogram", "h.csv", "--example"].map(String::from)).is_err());
+    let args = ["--histogram", "-", "--size", "1000000000000"];
+    assert!(parse_args(args.map(String::from)).is_err());
+    assert!(parse_args(["--start".to_string()]).is_err());
+    assert!(parse_args(["--start", "100"].map(String::from)).is_err());
 }
@@ -1,104 +1,7 @@
-use std::{io::Read, time::Instant};
+use std::process::ExitCode;
 
-fn parse_input(input: &str) -> Vec<Vec<u8>> {
-    input.lines().map(|line| line.as_bytes().to_vec()).collect()
-}
-
-fn nof_neighbors(grid: &[Vec<u8>], row: usize, col: usize) -> usize {
-    let width = grid[0].len();
-    let height = grid.len();
-
-    let mut result = 0;
-
-    for y in row.saturating_sub(1)..height.min(row + 2) {
-        for x in col.saturating_sub(1)..width.min(col + 2) {
-            if (y != row || x != col) && grid[y][x] == b'@' {
-                result += 1;
-            }
-        }
-    }
-
-    result
-}
-
-fn part1(grid: &[Vec<u8>]) -> usize {
-    let mut result = 0;
-    for row in 0..grid.len() {
-        for col in 0..grid[0].len() {
-            if grid[row][col] == b'@' && nof_neighbors(grid, row, col) < 4 {
-                result += 1;
-            }
-        }
-    }
-    result
-}
-
-fn part2(mut grid: Vec<Vec<u8>>) -> usize {
-    let mut result = 0;
-
-    let mut roll_has_been_removed = true;
-
-    let width = grid[0].len();
-    let height = grid.len();
-
-    while roll_has_been_removed {
-        roll_has_been_removed = false;
-        for row in 0..height {
-            for col in 0..width {
-                if grid[row][col] == b'@' && nof_neighbors(&grid, row, col) < 4 {
-                    result += 1;
-                    roll_has_been_removed = true;
-                    grid[row][col] = b'.';
-                }
-            }
-        }
-    }
-    result
-}
-
-fn main() {
-    let mut input = String::new();
-    std::io::stdin()
-        .read_to_string(&mut input)
-        .expect("reading input must work");
-
-    let before_parse = Instant::now();
-    let grid = parse_input(&input);
-    let parse_time = before_parse.elapsed();
-
-    let before_part1 = Instant::now();
-    println!("Part 1: {}", part1(&grid));
-    let part1_time = before_part1.elapsed();
-
-    let before_part2 = Instant::now();
-    println!("Part 2: {}", part2(grid));
-    let part2_time = before_part2.elapsed();
-
-    println!("==========================================");
-    println!("Parsing: {} µs", parse_time.as_micros());
-    println!("Part 1 : {} µs", part1_time.as_micros());
-    println!("Part 2 : {} µs", part2_time.as_micros());
-    println!(
-        "Total  : {} µs",
-        parse_time.as_micros() + part1_time.as_micros() + part2_time.as_micros()
-    );
-}
-
-#[test]
-fn test_part1() {
-    let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-
-    let grid = parse_input(&input);
+use advent_of_code_2025::{day04::Day04, run};
 
-    assert_eq!(nof_neighbors(&grid, 0, 2), 3);
-    assert_eq!(part1(&grid), 13);
+fn main() -> ExitCode {
+    run::<Day04>()
 }
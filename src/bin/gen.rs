@@ -0,0 +1,85 @@
+use std::process::ExitCode;
+
+use advent_of_code_2025::{generate::generator, rng::Rng};
+
+const USAGE: &str = "usage: gen DAY [--size N] [--seed SEED]
+
+Prints a random puzzle input for DAY. N items are generated, e.g. rotations, ranges, grid rows or
+devices, by default about as many as in the puzzle inputs. The same SEED (default 0) always gives
+the same input.";
+
+struct Options {
+    day: u8,
+    size: Option<usize>,
+    seed: u64,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = 0;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" | "-n" => {
+                size = Some(
+                    args.next()
+                        .and_then(|n| n.parse().ok())
+                        .ok_or("--size expects a number of items")?,
+                );
+            }
+            "--seed" | "-s" => {
+                seed = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .ok_or("--seed expects a number")?;
+            }
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`\n{USAGE}")),
+            _ if day.is_some() => return Err(format!("only one day can be generated\n{USAGE}")),
+            _ => match arg.parse() {
+                Ok(d) if generator(d).is_some() => day = Some(d),
+                _ => return Err(format!("invalid day `{arg}`\n{USAGE}")),
+            },
+        }
+    }
+
+    Ok(Options {
+        day: day.ok_or(USAGE)?,
+        size,
+        seed,
+    })
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (generate, default_size) = generator(options.day).expect("days are validated");
+    let input = generate(
+        options.size.unwrap_or(default_size),
+        &mut Rng::new(options.seed),
+    );
+    print!("{input}");
+
+    ExitCode::SUCCESS
+}
+
+#[test]
+fn test_parse_args() {
+    let options = parse_args(["7", "--seed", "42"].map(String::from)).unwrap();
+    assert_eq!((options.day, options.size, options.seed), (7, None, 42));
+    assert_eq!(
+        parse_args(["-n", "5", "1"].map(String::from)).unwrap().size,
+        Some(5)
+    );
+    assert!(parse_args(["13".to_string()]).is_err());
+    assert!(parse_args(["1", "2"].map(String::from)).is_err());
+    assert!(parse_args([]).is_err());
+}
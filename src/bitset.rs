@@ -0,0 +1,208 @@
+use std::{
+    fmt::Display,
+    ops::{BitAnd, BitOr, BitXor, BitXorAssign},
+};
+
+use crate::{ParseError, parse::Source};
+
+/// A set of indices of any size stored as bits. Trailing zero words are never stored, so that
+/// equal sets are equal and hash the same regardless of how they were built.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    fn location(idx: usize) -> (usize, u64) {
+        (idx / 64, 1 << (idx % 64))
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        let (word, bit) = Self::location(idx);
+        self.words.get(word).is_some_and(|w| w & bit != 0)
+    }
+
+    pub fn insert(&mut self, idx: usize) {
+        let (word, bit) = Self::location(idx);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= bit;
+    }
+
+    pub fn remove(&mut self, idx: usize) {
+        let (word, bit) = Self::location(idx);
+        if let Some(w) = self.words.get_mut(word) {
+            *w &= !bit;
+        }
+        self.trim();
+    }
+
+    pub fn toggle(&mut self, idx: usize) {
+        if self.contains(idx) {
+            self.remove(idx);
+        } else {
+            self.insert(idx);
+        }
+    }
+
+    /// The number of indices in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The indices in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(idx, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| idx * 64 + bit)
+        })
+    }
+
+    /// Parses a diagram like `[.##.]`, `#` marks the indices in the set.
+    pub fn parse_diagram(source: &Source, s: &str) -> Result<Self, ParseError> {
+        let diagram = source.delimited(s, '[', ']')?;
+
+        let mut set = Self::new();
+        for (idx, (offset, c)) in diagram.char_indices().enumerate() {
+            match c {
+                '#' => set.insert(idx),
+                '.' => {}
+                _ => {
+                    return Err(source.error(
+                        &diagram[offset..offset + c.len_utf8()],
+                        "expected `#` or `.`",
+                    ));
+                }
+            }
+        }
+        Ok(set)
+    }
+
+    /// Parses a list of indices like `(1,3)`, which must all be below `len`.
+    pub fn parse_indices(source: &Source, s: &str, len: usize) -> Result<Self, ParseError> {
+        let indices = source.delimited(s, '(', ')')?;
+
+        let mut set = Self::new();
+        for idx in indices.split(',') {
+            let number = source.number(idx)?;
+            if number >= len {
+                return Err(source.error(idx, format!("expected a light index below {len}")));
+            }
+            set.insert(number);
+        }
+        Ok(set)
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let len = self.words.len().max(other.words.len());
+        let word = |words: &[u64], idx| words.get(idx).copied().unwrap_or(0);
+
+        let mut set = Self {
+            words: (0..len)
+                .map(|idx| f(word(&self.words, idx), word(&other.words, idx)))
+                .collect(),
+        };
+        set.trim();
+        set
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for idx in iter {
+            set.insert(idx);
+        }
+        set
+    }
+}
+
+impl BitXor for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, other: Self) -> BitSet {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+}
+
+impl BitXorAssign<&BitSet> for BitSet {
+    fn bitxor_assign(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word ^= other;
+        }
+        self.trim();
+    }
+}
+
+impl BitOr for &BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, other: Self) -> BitSet {
+        self.zip_with(other, |a, b| a | b)
+    }
+}
+
+impl BitAnd for &BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, other: Self) -> BitSet {
+        self.zip_with(other, |a, b| a & b)
+    }
+}
+
+/// Prints the indices like `(1,3)`.
+impl Display for BitSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let indices: Vec<_> = self.iter().map(|idx| idx.to_string()).collect();
+        write!(f, "({})", indices.join(","))
+    }
+}
+
+#[test]
+fn test_bitset() {
+    let input = "[.##.] (1,3) (2,70) [.x]";
+    let source = Source::new(10, input);
+    let tokens: Vec<_> = input.split(' ').collect();
+
+    let lights = BitSet::parse_diagram(&source, tokens[0]).unwrap();
+    let button = BitSet::parse_indices(&source, tokens[1], 4).unwrap();
+    let wide_button = BitSet::parse_indices(&source, tokens[2], 71).unwrap();
+    assert_eq!(lights, BitSet::from_iter([1, 2]));
+    assert_eq!(wide_button.to_string(), "(2,70)");
+    assert!(wide_button.contains(70) && !wide_button.contains(6));
+
+    assert_eq!(&lights ^ &button, BitSet::from_iter([2, 3]));
+    assert_eq!(&lights | &wide_button, BitSet::from_iter([1, 2, 70]));
+    assert_eq!(&lights & &button, BitSet::from_iter([1]));
+
+    let mut state = wide_button.clone();
+    state ^= &lights;
+    assert_eq!(state.len(), 2);
+    state ^= &BitSet::from_iter([1, 70]);
+    assert!(state.is_empty());
+    assert_eq!(state, BitSet::new());
+
+    let error = BitSet::parse_diagram(&source, tokens[3]).unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (23, "x"));
+    let error = BitSet::parse_indices(&source, tokens[2], 70).unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (17, "70"));
+}
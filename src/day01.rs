@@ -0,0 +1,510 @@
+use std::io::BufRead;
+
+use crate::{ParseError, Solution, parse::Source};
+
+/// Parses a rotation like `L68` or `R 14`, left rotations are negative.
+pub fn parse_rotation(source: &Source, line: &str) -> Result<i64, ParseError> {
+    let line = line.trim();
+
+    let (factor, amount) = match line.chars().next() {
+        Some('L') => (-1, &line[1..]),
+        Some('R') => (1, &line[1..]),
+        Some(c) => return Err(source.error(&line[..c.len_utf8()], "expected `L` or `R`")),
+        None => return Err(source.error(line, "expected a rotation like `L68`")),
+    };
+
+    let amount = amount.trim_start();
+    if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(source.error(amount, "expected a non-negative integer"));
+    }
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| source.error(amount, format!("expected at most {}", i64::MAX)))?;
+
+    Ok(factor * amount)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let source = Source::new(Day01::DAY, input);
+
+    input
+        .lines()
+        .map(|line| parse_rotation(&source, line))
+        .collect()
+}
+
+/// The dial of the safe. The puzzle's has 100 positions, starts at 50 and the password counts how
+/// often it points at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    pub size: i64,
+    pub start: i64,
+    /// The positions that are counted, sorted and without duplicates.
+    pub targets: Vec<i64>,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self {
+            size: 100,
+            start: 50,
+            targets: vec![0],
+        }
+    }
+}
+
+impl Dial {
+    pub fn new(size: i64, start: i64, mut targets: Vec<i64>) -> Result<Self, String> {
+        if size <= 0 {
+            return Err(format!("the dial size must be positive, found {size}"));
+        }
+        if let Some(position) = [start]
+            .iter()
+            .chain(&targets)
+            .find(|p| !(0..size).contains(p))
+        {
+            return Err(format!(
+                "dial positions must be between 0 and {}, found {position}",
+                size - 1
+            ));
+        }
+
+        targets.sort_unstable();
+        targets.dedup();
+        Ok(Self {
+            size,
+            start,
+            targets,
+        })
+    }
+
+    pub fn is_target(&self, position: i64) -> bool {
+        self.targets.binary_search(&position).is_ok()
+    }
+
+    /// The position after rotating from `position`.
+    pub fn rotate(&self, position: i64, rotation: i64) -> i64 {
+        // without overflowing for any size
+        let distance_to_end = self.size - position;
+        let rotation = rotation.rem_euclid(self.size);
+        if rotation >= distance_to_end {
+            rotation - distance_to_end
+        } else {
+            position + rotation
+        }
+    }
+
+    /// The start and every position after a rotation.
+    pub fn positions(&self, rotations: &[i64]) -> Vec<i64> {
+        let mut positions = vec![self.start];
+
+        for &rotation in rotations {
+            let position = positions[positions.len() - 1];
+            positions.push(self.rotate(position, rotation));
+        }
+
+        positions
+    }
+
+    /// The number of times the dial points at a target while it is rotated from `position`,
+    /// including where it stops but not where it starts.
+    pub fn target_passes(&self, position: i64, rotation: i64) -> usize {
+        let steps = rotation.unsigned_abs() as usize;
+
+        self.targets
+            .iter()
+            .map(|&target| {
+                // the distance to the target in the direction of the rotation, a full turn if the
+                // dial points at it
+                let distance = if rotation >= 0 {
+                    target - position
+                } else {
+                    position - target
+                };
+                let distance = match distance.rem_euclid(self.size) {
+                    0 => self.size as usize,
+                    distance => distance as usize,
+                };
+
+                if steps < distance {
+                    0
+                } else {
+                    (steps - distance) / self.size as usize + 1
+                }
+            })
+            .sum()
+    }
+}
+
+/// The number of rotations that leave the dial pointing at a target.
+pub fn part1(rotations: &[i64], dial: &Dial) -> usize {
+    dial.positions(rotations)
+        .iter()
+        .skip(1)
+        .filter(|&&position| dial.is_target(position))
+        .count()
+}
+
+/// The number of times the dial points at a target, also during rotations.
+pub fn part2(rotations: &[i64], dial: &Dial) -> usize {
+    let mut position = dial.start;
+    let mut passes = 0;
+
+    for &rotation in rotations {
+        passes += dial.target_passes(position, rotation);
+        position = dial.rotate(position, rotation);
+    }
+
+    passes
+}
+
+/// A rotation of the dial, `passes` is how often it pointed at a target like in part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub rotation: i64,
+    pub from: i64,
+    pub to: i64,
+    pub passes: usize,
+}
+
+/// Every rotation with the positions from `Dial::positions`.
+pub fn trace(rotations: &[i64], dial: &Dial) -> Vec<Step> {
+    rotations
+        .iter()
+        .zip(dial.positions(rotations).windows(2))
+        .map(|(&rotation, positions)| Step {
+            rotation,
+            from: positions[0],
+            to: positions[1],
+            passes: dial.target_passes(positions[0], rotation),
+        })
+        .collect()
+}
+
+/// The trace as CSV with one row per input line. Rotations that passed a target are flagged, so
+/// that they can be checked against part 2.
+pub fn trace_csv(steps: &[Step]) -> String {
+    let mut csv = String::from("line,rotation,from,to,passes,flag\n");
+
+    for (idx, step) in steps.iter().enumerate() {
+        let direction = if step.rotation < 0 { 'L' } else { 'R' };
+        let flag = if step.passes > 0 { "target" } else { "" };
+        csv.push_str(&format!(
+            "{},{direction}{},{},{},{},{flag}\n",
+            idx + 1,
+            step.rotation.unsigned_abs(),
+            step.from,
+            step.to,
+            step.passes
+        ));
+    }
+
+    csv
+}
+
+/// How often the dial stopped at every position, and how often it passed over it without
+/// stopping. The start position is in neither.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub landed: Vec<usize>,
+    pub passed: Vec<usize>,
+}
+
+impl Histogram {
+    /// Takes time and memory linear in the number of rotations and the size of the dial, but not
+    /// in the length of the rotations.
+    pub fn new(rotations: &[i64], dial: &Dial) -> Self {
+        let size = dial.size as usize;
+        let mut landed = vec![0; size];
+        // the passed counts as differences to the previous position, so that every rotation is a
+        // range update
+        let mut changes = vec![0_isize; size + 1];
+        // full turns pass every position once, they are added at the end
+        let mut full_turns = 0;
+
+        for step in trace(rotations, dial) {
+            landed[step.to as usize] += 1;
+
+            let between = (step.rotation.unsigned_abs() as usize).saturating_sub(1);
+            full_turns += between / size;
+            let len = between % size;
+            if len == 0 {
+                continue;
+            }
+            let first = if step.rotation > 0 {
+                dial.rotate(step.from, 1)
+            } else {
+                dial.rotate(step.from, -(len as i64))
+            } as usize;
+
+            changes[first] += 1;
+            if first + len <= size {
+                changes[first + len] -= 1;
+            } else {
+                // the range wraps around to 0
+                changes[0] += 1;
+                changes[first + len - size] -= 1;
+            }
+        }
+
+        let passed = changes[..size]
+            .iter()
+            .scan(0, |count, change| {
+                *count += change;
+                Some(*count as usize + full_turns)
+            })
+            .collect();
+
+        Self { landed, passed }
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("position,landed,passed\n");
+
+        for (position, (landed, passed)) in self.landed.iter().zip(&self.passed).enumerate() {
+            csv.push_str(&format!("{position},{landed},{passed}\n"));
+        }
+
+        csv
+    }
+}
+
+/// Counts the answers of both parts while the rotations are given one at a time, in constant
+/// memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter {
+    pub dial: Dial,
+    pub position: i64,
+    pub rotations: usize,
+    pub part1: usize,
+    pub part2: usize,
+}
+
+impl Counter {
+    pub fn new(dial: Dial) -> Self {
+        Self {
+            position: dial.start,
+            dial,
+            rotations: 0,
+            part1: 0,
+            part2: 0,
+        }
+    }
+
+    pub fn rotate(&mut self, rotation: i64) {
+        self.part2 += self.dial.target_passes(self.position, rotation);
+        self.position = self.dial.rotate(self.position, rotation);
+        self.part1 += usize::from(self.dial.is_target(self.position));
+        self.rotations += 1;
+    }
+}
+
+/// Reads the rotations line by line and counts them, only one line is kept in memory. Errors are
+/// rendered like `ParseError::diagnostic`.
+pub fn stream(mut reader: impl BufRead, dial: Dial) -> Result<Counter, String> {
+    let mut counter = Counter::new(dial);
+    let mut line = String::new();
+
+    for number in 1.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(error) => return Err(format!("error: cannot read line {number}: {error}\n")),
+        }
+
+        let line = line.trim_end_matches(['\n', '\r']);
+        let rotation = parse_rotation(&Source::new(Day01::DAY, line), line)
+            .map_err(|error| error.at_line(number).line_diagnostic(line))?;
+        counter.rotate(rotation);
+    }
+
+    Ok(counter)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i64>, Dial);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_input(input)?, Dial::default()))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = include_str!("../fixtures/day01.txt");
+
+    assert_eq!(part1(&parse_input(input).unwrap(), &Dial::default()), 3);
+}
+
+#[test]
+fn test_part2() {
+    let input = include_str!("../fixtures/day01.txt");
+
+    assert_eq!(part2(&parse_input(input).unwrap(), &Dial::default()), 6);
+}
+
+#[test]
+fn test_target_passes() {
+    let dial = Dial::default();
+    assert_eq!(dial.target_passes(50, 50), 1);
+    assert_eq!(dial.target_passes(50, -49), 0);
+    assert_eq!(dial.target_passes(0, -1), 0);
+    assert_eq!(dial.target_passes(0, 100), 1);
+    assert_eq!(dial.target_passes(99, 1), 1);
+    assert_eq!(dial.target_passes(50, 1000), 10);
+    assert_eq!(part2(&[i64::MAX, -i64::MAX], &dial), 2 * 92233720368547758);
+
+    // the same as turning the dial step by step
+    let mut rng = crate::rng::Rng::new(1);
+    for size in [1, 7, 100] {
+        let targets = (0..3).map(|_| rng.range(0..=size - 1) as i64).collect();
+        let dial = Dial::new(size as i64, 0, targets).unwrap();
+
+        for _ in 0..1000 {
+            let position = rng.range(0..=size - 1) as i64;
+            let rotation = rng.range(0..=500) as i64 - 250;
+            let steps = (1..=rotation.abs())
+                .filter(|step| dial.is_target(dial.rotate(position, step * rotation.signum())))
+                .count();
+            assert_eq!(dial.target_passes(position, rotation), steps);
+        }
+    }
+}
+
+#[test]
+fn test_dial() {
+    let input = include_str!("../fixtures/day01.txt");
+    let rotations = parse_input(input).unwrap();
+
+    let dial = Dial::new(100, 50, vec![0, 32]).unwrap();
+    assert_eq!(dial.positions(&rotations)[..4], [50, 82, 52, 0]);
+    assert_eq!(part1(&rotations, &dial), 4);
+    assert_eq!(part2(&rotations, &dial), 11);
+
+    let dial = Dial::new(10, 0, vec![0]).unwrap();
+    assert_eq!(part1(&rotations, &dial), 3);
+
+    assert!(Dial::new(0, 0, vec![0]).is_err());
+    assert!(Dial::new(10, 10, vec![0]).is_err());
+    assert!(Dial::new(10, 0, vec![-1]).is_err());
+}
+
+#[test]
+fn test_stream() {
+    let input = include_str!("../fixtures/day01.txt");
+
+    let counter = stream(input.as_bytes(), Dial::default()).unwrap();
+    assert_eq!(
+        (counter.rotations, counter.part1, counter.part2),
+        (10, 3, 6)
+    );
+    assert_eq!(counter.position, 32);
+
+    let counter = stream("R50\r\nL100\r\n".as_bytes(), Dial::default()).unwrap();
+    assert_eq!((counter.part1, counter.part2), (2, 2));
+
+    let error = stream("L68\nL30\nRx8\n".as_bytes(), Dial::default()).unwrap_err();
+    assert!(error.starts_with("error: day 1, line 3, column 2: expected a non-negative integer"));
+    assert!(error.ends_with("3 | Rx8\n  |  ^^\n"));
+}
+
+#[test]
+fn test_trace() {
+    let input = include_str!("../fixtures/day01.txt");
+    let rotations = parse_input(input).unwrap();
+    let dial = Dial::default();
+
+    let steps = trace(&rotations, &dial);
+    assert_eq!(
+        steps[0],
+        Step {
+            rotation: -68,
+            from: 50,
+            to: 82,
+            passes: 1
+        }
+    );
+    assert_eq!(steps.iter().map(|s| s.passes).sum::<usize>(), 6);
+
+    let csv = trace_csv(&steps);
+    assert_eq!(csv.lines().count(), 11);
+    assert!(csv.starts_with("line,rotation,from,to,passes,flag\n1,L68,50,82,1,target\n"));
+    assert!(csv.contains("\n2,L30,82,52,0,\n"));
+
+    let histogram = Histogram::new(&rotations, &dial);
+    assert_eq!(histogram.landed[0], part1(&rotations, &dial));
+    assert_eq!(
+        histogram.landed[0] + histogram.passed[0],
+        part2(&rotations, &dial)
+    );
+    assert_eq!(histogram.landed.iter().sum::<usize>(), rotations.len());
+    let steps: usize = rotations.iter().map(|r| r.unsigned_abs() as usize).sum();
+    assert_eq!(
+        histogram.passed.iter().sum::<usize>(),
+        steps - rotations.len()
+    );
+
+    let histogram = Histogram::new(&[250], &Dial::new(10, 0, vec![0]).unwrap());
+    assert_eq!((histogram.landed[0], histogram.passed[0]), (1, 24));
+    assert_eq!(histogram.passed[1], 25);
+    assert!(
+        histogram
+            .to_csv()
+            .starts_with("position,landed,passed\n0,1,24\n1,0,25\n")
+    );
+
+    let histogram = Histogram::new(&[7, -9], &Dial::new(10, 5, vec![0]).unwrap());
+    assert_eq!(histogram.passed, [2, 2, 0, 0, 1, 1, 2, 2, 2, 2]);
+    assert_eq!(histogram.landed, [0, 0, 1, 1, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_parse_rotation() {
+    let parse = |line| parse_input(line).map_err(|error| (error.column, error.message));
+
+    assert_eq!(
+        parse("L68\nR 14\n  L\t5 \nR9223372036854775807"),
+        Ok(vec![-68, 14, -5, i64::MAX])
+    );
+    assert_eq!(parse("LRL5").unwrap_err().0, 2);
+    assert_eq!(
+        parse("X5").unwrap_err(),
+        (1, "expected `L` or `R`".to_string())
+    );
+    assert_eq!(parse("R-5").unwrap_err().0, 2);
+    assert_eq!(parse("R+5").unwrap_err().0, 2);
+    assert_eq!(parse("R5 6").unwrap_err().0, 2);
+    assert_eq!(parse("L").unwrap_err().0, 2);
+    assert!(
+        parse("R9223372036854775808")
+            .unwrap_err()
+            .1
+            .starts_with("expected at most")
+    );
+
+    let error = parse_input("L68\n\nR5").unwrap_err();
+    assert_eq!(
+        (error.line, error.message.as_str()),
+        (2, "expected a rotation like `L68`")
+    );
+    let error = parse_input("L68\nR5\n r 5").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.token.as_str()),
+        (3, 2, "r")
+    );
+}
@@ -0,0 +1,249 @@
+use std::ops::{RangeInclusive, Rem};
+
+use crate::{ParseError, Solution, interval_set::IntervalSet, parallel, parse::Source};
+
+/// The ID ranges as they are given, an ID in several of them is counted once per range.
+pub fn parse_input(input: &str) -> Result<Vec<RangeInclusive<u64>>, ParseError> {
+    let source = Source::new(Day02::DAY, input);
+
+    input
+        .trim()
+        .split(',')
+        .map(|part| {
+            let (start, end) = source.split_once(part.trim(), '-')?;
+            let (start, end) = (source.number(start)?, source.number(end)?);
+
+            if start > end {
+                return Err(source.error(part.trim(), "range start is larger than its end"));
+            }
+
+            Ok(start..=end)
+        })
+        .collect()
+}
+
+pub fn nof_digits(mut id: u64) -> u32 {
+    let mut result = 0;
+
+    while id > 0 {
+        result += 1;
+        id /= 10;
+    }
+
+    result
+}
+
+pub fn is_invalid(id: u64, max_pattern_length: u32) -> bool {
+    let nof_id_digits = nof_digits(id);
+
+    for pattern_length in 1..=max_pattern_length {
+        let pattern = id.rem(10_u64.pow(pattern_length));
+        let mut number = 0;
+
+        if pattern == 0 {
+            continue;
+        }
+
+        while nof_digits(number) < nof_id_digits {
+            number = (number * 10_u64.pow(pattern_length)) + pattern;
+        }
+
+        if number == id {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// The IDs with the given number of digits.
+pub fn ids_with_digits(digits: u32) -> IntervalSet {
+    let end = 10_u64.checked_pow(digits).map_or(u64::MAX, |n| n - 1);
+    IntervalSet::from_iter([10_u64.pow(digits - 1)..=end])
+}
+
+/// Splits the IDs from `start` to `end` into ranges of IDs with the same number of digits and sums
+/// `sum` over them. 0 is left out, it is never invalid.
+fn sum_by_nof_digits(start: u64, end: u64, sum: fn(u64, u64) -> u64) -> u64 {
+    let ids = IntervalSet::from_iter([start.max(1)..=end]);
+
+    (1..=nof_digits(end))
+        .map(|digits| {
+            ids.intersection(&ids_with_digits(digits))
+                .iter()
+                .map(|range| sum(*range.start(), *range.end()))
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+pub fn sum_of_invalid_ids_part1(start: u64, end: u64) -> u64 {
+    // the search below assumes that all IDs have the same number of digits
+    if nof_digits(start) != nof_digits(end) {
+        return sum_by_nof_digits(start, end, sum_of_invalid_ids_part1);
+    }
+
+    let mut sum = 0;
+
+    let start_nof_digits = nof_digits(start);
+
+    let nof_digits_to_shift = (start_nof_digits / 2) + start_nof_digits.rem(2);
+
+    let mut prefix = start / 10_u64.pow(nof_digits_to_shift);
+
+    while (prefix * 10_u64.pow(nof_digits(prefix)) + prefix) <= end {
+        if (prefix * 10_u64.pow(nof_digits(prefix)) + prefix) >= start {
+            sum += prefix * 10_u64.pow(nof_digits(prefix)) + prefix;
+        }
+        prefix += 1;
+    }
+
+    sum
+}
+
+pub fn sum_of_invalid_ids_part2(start: u64, end: u64) -> u64 {
+    // the search below assumes that all IDs have the same number of digits
+    if nof_digits(start) != nof_digits(end) {
+        return sum_by_nof_digits(start, end, sum_of_invalid_ids_part2);
+    }
+
+    let mut sum = 0;
+
+    let start_nof_digits = nof_digits(start);
+    let end_nof_digits = nof_digits(end);
+
+    let mut pattern = 1;
+
+    loop {
+        let pattern_length = nof_digits(pattern);
+
+        if is_invalid(pattern, pattern_length - 1) {
+            pattern += 1;
+            continue;
+        }
+
+        if pattern_length * 2 > end_nof_digits {
+            break;
+        }
+
+        let start_prefix = start / 10_u64.pow(start_nof_digits - pattern_length);
+        let end_prefix = end / 10_u64.pow(end_nof_digits - pattern_length);
+
+        if pattern > start_prefix && pattern > end_prefix {
+            pattern = 10_u64.pow(pattern_length);
+            continue;
+        }
+
+        let mut number = 0;
+        while nof_digits(number) < start_nof_digits {
+            number = (number * 10_u64.pow(pattern_length)) + pattern;
+        }
+
+        while nof_digits(number) <= end_nof_digits {
+            if number >= start && number <= end && !is_invalid(number, pattern_length - 1) {
+                sum += number;
+            }
+            number = (number * 10_u64.pow(pattern_length)) + pattern;
+        }
+
+        pattern += 1;
+    }
+
+    sum
+}
+
+pub fn part1(ranges: &[RangeInclusive<u64>]) -> u64 {
+    parallel::sum(ranges, |range| {
+        sum_of_invalid_ids_part1(*range.start(), *range.end())
+    })
+}
+
+pub fn part2(ranges: &[RangeInclusive<u64>]) -> u64 {
+    parallel::sum(ranges, |range| {
+        sum_of_invalid_ids_part2(*range.start(), *range.end())
+    })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<RangeInclusive<u64>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(sum_of_invalid_ids_part1(11, 22), 11 + 22);
+    assert_eq!(sum_of_invalid_ids_part1(95, 115), 99);
+    assert_eq!(sum_of_invalid_ids_part1(998, 1012), 1010);
+    assert_eq!(sum_of_invalid_ids_part1(1188511880, 1188511890), 1188511885);
+    assert_eq!(sum_of_invalid_ids_part1(222220, 222224), 222222);
+    assert_eq!(sum_of_invalid_ids_part1(1698522, 1698528), 0);
+    assert_eq!(sum_of_invalid_ids_part1(446443, 446449), 446446);
+    assert_eq!(sum_of_invalid_ids_part1(38593856, 38593862), 38593859);
+
+    let input = include_str!("../fixtures/day02.txt");
+
+    let ranges = parse_input(input).unwrap();
+
+    assert_eq!(part1(&ranges), 1227775554);
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(sum_of_invalid_ids_part2(11, 22), 11 + 22);
+    assert_eq!(sum_of_invalid_ids_part2(95, 115), 99 + 111);
+    assert_eq!(sum_of_invalid_ids_part2(998, 1012), 999 + 1010);
+    assert_eq!(sum_of_invalid_ids_part2(1188511880, 1188511890), 1188511885);
+    assert_eq!(sum_of_invalid_ids_part2(222220, 222224), 222222);
+    assert_eq!(sum_of_invalid_ids_part2(1698522, 1698528), 0);
+    assert_eq!(sum_of_invalid_ids_part2(446443, 446449), 446446);
+    assert_eq!(sum_of_invalid_ids_part2(38593856, 38593862), 38593859);
+    assert_eq!(sum_of_invalid_ids_part2(565653, 565659), 565656);
+    assert_eq!(sum_of_invalid_ids_part2(824824821, 824824827), 824824824);
+    assert_eq!(sum_of_invalid_ids_part2(2121212118, 2121212124), 2121212121);
+
+    let input = include_str!("../fixtures/day02.txt");
+    let ranges = parse_input(input).unwrap();
+    assert_eq!(part2(&ranges), 4174379265);
+}
+
+#[test]
+fn test_ranges_across_digit_lengths() {
+    assert_eq!(ids_with_digits(2).iter().collect::<Vec<_>>(), [10..=99]);
+    assert_eq!(
+        ids_with_digits(20).iter().collect::<Vec<_>>(),
+        [10_000_000_000_000_000_000..=u64::MAX]
+    );
+    assert_eq!(sum_by_nof_digits(0, 0, |_, _| 1), 0);
+    assert_eq!(
+        sum_by_nof_digits(5, 1000, |start, _| start),
+        5 + 10 + 100 + 1000
+    );
+
+    let ranges = parse_input("5-9,10-1000").unwrap();
+    assert_eq!(ranges, vec![5..=9, 10..=1000]);
+    let repeated_twice = (1..=9).map(|d| d * 11).sum::<u64>();
+    assert_eq!(part1(&ranges), repeated_twice);
+    assert_eq!(part2(&ranges), repeated_twice + 111 * (1..=9).sum::<u64>());
+    assert_eq!(part1(&parse_input("5-1000").unwrap()), part1(&ranges));
+    assert_eq!(part2(&parse_input("5-1000").unwrap()), part2(&ranges));
+
+    // an ID in two ranges is counted for both
+    assert_eq!(part1(&parse_input("11-22,22-22").unwrap()), 11 + 22 + 22);
+}
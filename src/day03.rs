@@ -0,0 +1,134 @@
+use crate::{Maybe, ParseError, Solution, parallel, parse::Source};
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    let source = Source::new(Day03::DAY, input);
+
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.char_indices()
+                .map(|(idx, c)| {
+                    c.to_digit(10).map(u64::from).ok_or_else(|| {
+                        source.error(&line[idx..idx + c.len_utf8()], "expected a digit")
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The largest joltage of `nof_batteries` batteries of the bank, at most 12. `None` if the bank has
+/// fewer batteries.
+pub fn maximum_joltage(batteries: &[u64], nof_batteries: usize) -> Option<u64> {
+    if batteries.len() < nof_batteries {
+        return None;
+    }
+
+    let mut max_digit_indices = [0; 12];
+
+    // fill each index
+    for digit_index in 0..nof_batteries {
+        let start_index = if digit_index > 0 {
+            max_digit_indices[digit_index - 1] + 1
+        } else {
+            0
+        };
+
+        max_digit_indices[digit_index] = start_index;
+
+        for battery_index in start_index..batteries.len() - nof_batteries + digit_index + 1 {
+            if batteries[battery_index] > batteries[max_digit_indices[digit_index]] {
+                max_digit_indices[digit_index] = battery_index;
+            }
+        }
+    }
+
+    let mut result = 0;
+    for &d in &max_digit_indices[..nof_batteries] {
+        result = result * 10 + batteries[d];
+    }
+
+    Some(result)
+}
+
+/// `None` if any bank has fewer than 2 batteries.
+pub fn part1(batteries: &[Vec<u64>]) -> Option<u64> {
+    parallel::sum(batteries, |b| maximum_joltage(b, 2))
+}
+
+/// `None` if any bank has fewer than 12 batteries.
+pub fn part2(batteries: &[Vec<u64>]) -> Option<u64> {
+    parallel::sum(batteries, |b| maximum_joltage(b, 12))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u64>>;
+    type Part1 = Maybe<u64>;
+    type Part2 = Maybe<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        Maybe(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        Maybe(part2(input))
+    }
+}
+
+#[test]
+fn test_part_1() {
+    assert_eq!(
+        maximum_joltage(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1], 2),
+        Some(98)
+    );
+    assert_eq!(
+        maximum_joltage(&[8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9], 2),
+        Some(89)
+    );
+    assert_eq!(
+        maximum_joltage(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8], 2),
+        Some(78)
+    );
+    assert_eq!(
+        maximum_joltage(&[8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1], 2),
+        Some(92)
+    );
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        maximum_joltage(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1], 12),
+        Some(987654321111)
+    );
+    assert_eq!(
+        maximum_joltage(&[8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9], 12),
+        Some(811111111119)
+    );
+    assert_eq!(
+        maximum_joltage(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8], 12),
+        Some(434234234278)
+    );
+    assert_eq!(
+        maximum_joltage(&[8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1], 12),
+        Some(888911112111)
+    );
+}
+
+#[test]
+fn test_short_banks() {
+    let banks = parse_input("818181911112111\n91\n").unwrap();
+    assert_eq!(maximum_joltage(&banks[1], 2), Some(91));
+    assert_eq!(maximum_joltage(&banks[1], 12), None);
+    assert_eq!(part1(&banks), Some(92 + 91));
+    assert_eq!(part2(&banks), None);
+}
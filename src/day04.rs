@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use crate::{
+    ParseError, Solution,
+    grid::{Grid, Position},
+    parse::Source,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Roll,
+    Empty,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Roll => write!(f, "@"),
+            Self::Empty => write!(f, "."),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Cell>, ParseError> {
+    let source = Source::new(Day04::DAY, input);
+
+    Grid::parse(&source, input, "expected `@` or `.`", |c| match c {
+        '@' => Some(Cell::Roll),
+        '.' => Some(Cell::Empty),
+        _ => None,
+    })
+}
+
+pub fn nof_neighbors(grid: &Grid<Cell>, position: Position) -> usize {
+    grid.neighbors8(position)
+        .filter(|&neighbor| grid[neighbor] == Cell::Roll)
+        .count()
+}
+
+fn is_accessible(grid: &Grid<Cell>, position: Position) -> bool {
+    grid[position] == Cell::Roll && nof_neighbors(grid, position) < 4
+}
+
+pub fn part1(grid: &Grid<Cell>) -> usize {
+    grid.positions()
+        .filter(|&position| is_accessible(grid, position))
+        .count()
+}
+
+pub fn part2(grid: &Grid<Cell>) -> usize {
+    let mut grid = grid.clone();
+    let mut result = 0;
+
+    let mut roll_has_been_removed = true;
+
+    while roll_has_been_removed {
+        roll_has_been_removed = false;
+        for position in grid.positions() {
+            if is_accessible(&grid, position) {
+                result += 1;
+                roll_has_been_removed = true;
+                grid[position] = Cell::Empty;
+            }
+        }
+    }
+    result
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<Cell>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = include_str!("../fixtures/day04.txt");
+
+    let grid = parse_input(input).unwrap();
+
+    assert_eq!(nof_neighbors(&grid, (2, 0)), 3);
+    assert_eq!(part1(&grid), 13);
+}
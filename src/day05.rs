@@ -0,0 +1,73 @@
+use crate::{ParseError, Solution, interval_set::IntervalSet, parse::Source};
+
+pub fn parse_input(input: &str) -> Result<(IntervalSet, Vec<u64>), ParseError> {
+    let source = Source::new(Day05::DAY, input);
+
+    let mut ranges = Vec::new();
+    let mut ids = Vec::new();
+
+    for line in input.lines() {
+        if line.contains("-") {
+            let (start, end) = source.split_once(line, '-')?;
+
+            let start = source.number(start)?;
+            let end = source.number(end)?;
+
+            if start > end {
+                return Err(source.error(line, "range start is larger than its end"));
+            }
+
+            ranges.push(start..=end);
+        } else if line.is_empty() {
+            continue;
+        } else {
+            ids.push(source.number(line)?);
+        }
+    }
+
+    Ok((ranges.into_iter().collect(), ids))
+}
+
+pub fn is_fresh(id: u64, fresh: &IntervalSet) -> bool {
+    fresh.contains(id)
+}
+
+pub fn part1(fresh: &IntervalSet, ids: &[u64]) -> usize {
+    ids.iter().filter(|id| is_fresh(**id, fresh)).count()
+}
+
+pub fn part2(fresh: &IntervalSet) -> u64 {
+    fresh.len()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (IntervalSet, Vec<u64>);
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(&input.0)
+    }
+}
+#[test]
+fn test_part2() {
+    let part2 = |ranges: &[_]| part2(&ranges.iter().cloned().collect());
+
+    assert_eq!(part2(&[3..=5, 10..=14, 16..=20, 12..=18]), 14);
+    assert_eq!(part2(&[3..=5, 4..=4]), 3);
+    assert_eq!(part2(&[4..=4, 3..=5]), 3);
+
+    assert_eq!(part2(&[1..=1, 3..=3, 1..=3]), 3);
+}
@@ -0,0 +1,165 @@
+use crate::{ParseError, Solution, parse::Source};
+
+pub fn parse_input(input: &str) -> Result<(Vec<Vec<u64>>, Vec<char>), ParseError> {
+    let source = Source::new(Day06::DAY, input);
+
+    let mut operators: Vec<Vec<u64>> = vec![];
+    let mut operands = vec![];
+
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        if !operands.is_empty() {
+            return Err(source.error(line, "expected nothing after the operator line"));
+        }
+
+        if line.contains(['+', '*']) {
+            for (idx, c) in line.char_indices() {
+                match c {
+                    '+' | '*' => operands.push(c),
+                    c if c.is_whitespace() => {}
+                    _ => {
+                        return Err(
+                            source.error(&line[idx..idx + c.len_utf8()], "expected `+` or `*`")
+                        );
+                    }
+                }
+            }
+        } else {
+            operators.push(
+                line.split_whitespace()
+                    .map(|part| source.number(part))
+                    .collect::<Result<_, _>>()?,
+            );
+        }
+    }
+
+    if operands.is_empty() {
+        return Err(source.error(&input[input.len()..], "expected an operator line"));
+    }
+
+    for (row, line) in operators
+        .iter()
+        .zip(input.lines().filter(|l| !l.is_empty()))
+    {
+        if row.len() != operands.len() {
+            return Err(source.error(
+                line,
+                format!(
+                    "expected {} numbers like there are operators",
+                    operands.len()
+                ),
+            ));
+        }
+    }
+
+    Ok((operators, operands))
+}
+
+pub fn part1(operators: &[Vec<u64>], operands: &[char]) -> u64 {
+    operands
+        .iter()
+        .enumerate()
+        .map(|(idx, operand)| {
+            if operand == &'+' {
+                operators.iter().map(|o| o[idx]).sum::<u64>()
+            } else {
+                operators.iter().map(|o| o[idx]).product::<u64>()
+            }
+        })
+        .sum()
+}
+
+pub fn part2(input: &str) -> u64 {
+    let mut input: Vec<_> = input
+        .lines()
+        .filter_map(|l| {
+            if l.is_empty() {
+                None
+            } else {
+                Some(l.as_bytes())
+            }
+        })
+        .collect();
+    let operator_line = input.pop().unwrap();
+
+    let mut result = 0;
+    let mut current_operands = vec![0];
+    let mut current_operator = b'+';
+
+    for (idx, c) in operator_line.iter().enumerate() {
+        if !c.is_ascii_whitespace() {
+            current_operands.pop();
+
+            if current_operator == b'+' {
+                result += current_operands.iter().sum::<u64>();
+            } else {
+                result += current_operands.iter().product::<u64>();
+            }
+
+            current_operator = *c;
+            current_operands.clear();
+        }
+
+        // lines may be shorter than the operator line if their trailing whitespace was stripped
+        let column = input
+            .iter()
+            .map(|line| line.get(idx).copied().unwrap_or(b' '));
+        current_operands.push(column.fold(0, |acc, c| {
+            if c.is_ascii_whitespace() {
+                acc
+            } else {
+                acc * 10 + (c - b'0') as u64
+            }
+        }));
+    }
+
+    if current_operator == b'+' {
+        result += current_operands.iter().sum::<u64>();
+    } else {
+        result += current_operands.iter().product::<u64>();
+    }
+
+    result
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    // part 2 reads the worksheet column by column, so it works on the raw input
+    type Input = (Vec<Vec<u64>>, Vec<char>, String);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (operators, operands) = parse_input(input)?;
+        Ok((operators, operands, input.to_string()))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(&input.2)
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = include_str!("../fixtures/day06.txt");
+    let (operators, operands) = parse_input(input).unwrap();
+
+    assert_eq!(part1(&operators, &operands), 4277556);
+}
+
+#[test]
+fn test_part2() {
+    let input = include_str!("../fixtures/day06.txt");
+
+    assert_eq!(part2(input), 3263827);
+}
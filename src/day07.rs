@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use crate::{
+    ParseError, Solution,
+    grid::{Grid, Position},
+    memo::Memo,
+    parse::Source,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Start,
+    Empty,
+    Splitter,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Start => write!(f, "S"),
+            Self::Empty => write!(f, "."),
+            Self::Splitter => write!(f, "^"),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Cell>, ParseError> {
+    let source = Source::new(Day07::DAY, input);
+
+    let grid = Grid::parse(&source, input, "expected `S`, `.` or `^`", |c| match c {
+        'S' => Some(Cell::Start),
+        '.' => Some(Cell::Empty),
+        '^' => Some(Cell::Splitter),
+        _ => None,
+    })?;
+
+    if grid.find(|&cell| cell == Cell::Start).is_none() {
+        return Err(source.error(&input[..0], "expected a start `S`"));
+    }
+
+    Ok(grid)
+}
+
+fn start(grid: &Grid<Cell>) -> Position {
+    grid.find(|&cell| cell == Cell::Start)
+        .expect("the start is validated when parsing")
+}
+
+pub fn part1(grid: &Grid<Cell>) -> u64 {
+    let (start, start_row) = start(grid);
+
+    let mut number_of_splits = 0;
+
+    let mut beams = vec![false; grid.width()];
+    beams[start] = true;
+
+    for y in start_row + 1..grid.height() {
+        let mut new_beams = vec![false; grid.width()];
+
+        for x in (0..grid.width()).filter(|&x| beams[x]) {
+            if grid[(x, y)] == Cell::Splitter {
+                number_of_splits += 1;
+                for (x, _) in split(grid, (x, y)) {
+                    new_beams[x] = true;
+                }
+            } else {
+                new_beams[x] = true;
+            }
+        }
+
+        beams = new_beams;
+    }
+
+    number_of_splits
+}
+
+/// The positions left and right of a splitter, beams that leave the manifold to the side are lost.
+fn split(grid: &Grid<Cell>, position: Position) -> impl Iterator<Item = Position> {
+    [grid.step(position, (-1, 0)), grid.step(position, (1, 0))]
+        .into_iter()
+        .flatten()
+}
+
+pub fn part2(grid: &Grid<Cell>) -> u64 {
+    // the number of timelines of a particle at a position is the sum of the ones of the positions
+    // it can go to next, the recursion is as deep as the manifold is high
+    Memo::new().get_with_stack(
+        start(grid),
+        |&(x, y)| match grid.step((x, y), (0, 1)) {
+            None => vec![],
+            Some(below) if grid[below] == Cell::Splitter => split(grid, below).collect(),
+            Some(below) => vec![below],
+        },
+        |&(_, y), timelines: &[u64]| {
+            if y + 1 == grid.height() {
+                1
+            } else {
+                timelines.iter().sum()
+            }
+        },
+    )
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Grid<Cell>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
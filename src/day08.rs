@@ -0,0 +1,178 @@
+use crate::{ParseError, Solution, geometry::Point3, parse::Source, union_find::UnionFind};
+
+pub type Position = Point3;
+pub type JunctionBox = usize;
+pub type Connection = (JunctionBox, JunctionBox);
+
+const NOF_CONNECTIONS_PART1: usize = 1000;
+const NOF_CONNECTIONS_PART1_EXAMPLE: usize = 10;
+
+pub struct Playground {
+    pub positions: Vec<Position>,
+    // all possible connections, shortest first
+    pub connections: Vec<Connection>,
+    // how many of the shortest connections are made in part 1
+    pub nof_connections_part1: usize,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Position>, ParseError> {
+    let source = Source::new(Day08::DAY, input);
+
+    let positions: Vec<_> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (x, rest) = source.split_once(line, ',')?;
+            let (y, z) = source.split_once(rest, ',')?;
+
+            Ok(Point3::new(
+                source.non_negative_i64(x)?,
+                source.non_negative_i64(y)?,
+                source.non_negative_i64(z)?,
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+
+    if positions.len() < 2 {
+        return Err(source.error(&input[..0], "expected at least two junction boxes"));
+    }
+
+    Ok(positions)
+}
+
+/// All pairs of junction boxes with their squared distance, shortest first.
+pub fn compute_distances(positions: &[Position]) -> Vec<(JunctionBox, JunctionBox, u128)> {
+    let mut distances = vec![];
+    for (jb1, pos1) in positions.iter().enumerate() {
+        for (jb2, pos2) in positions.iter().enumerate().skip(jb1 + 1) {
+            distances.push((jb1, jb2, pos1.euclidean_squared(*pos2)));
+        }
+    }
+
+    distances.sort_unstable_by_key(|(_, _, d)| *d);
+    distances
+}
+
+/// The circuits formed by the given connections between `nof_junction_boxes` boxes.
+pub fn create_circuits(nof_junction_boxes: usize, connections: &[Connection]) -> UnionFind {
+    let mut circuits = UnionFind::new(nof_junction_boxes);
+    for &(jb1, jb2) in connections {
+        circuits.union(jb1, jb2);
+    }
+    circuits
+}
+
+/// The connection that joins all junction boxes into a single circuit.
+pub fn find_last_connection(
+    nof_junction_boxes: usize,
+    all_connections: &[Connection],
+) -> Connection {
+    let mut circuits = UnionFind::new(nof_junction_boxes);
+
+    all_connections
+        .iter()
+        .copied()
+        .find(|&(jb1, jb2)| circuits.union(jb1, jb2) && circuits.nof_components() == 1)
+        .expect("all possible connections join all junction boxes")
+}
+
+pub fn part1(circuits: &UnionFind) -> u64 {
+    circuits
+        .largest(3)
+        .iter()
+        .map(|&size| size as u64)
+        .product()
+}
+
+pub fn part2(all_connections: &[Connection], positions: &[Position]) -> i128 {
+    let last_connection = find_last_connection(positions.len(), all_connections);
+
+    i128::from(positions[last_connection.0].x) * i128::from(positions[last_connection.1].x)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+    type Part1 = u64;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let positions = parse_input(input)?;
+        let distances = compute_distances(&positions);
+        let connections = distances.iter().map(|(jb1, jb2, _)| (*jb1, *jb2)).collect();
+
+        Ok(Playground {
+            positions,
+            connections,
+            nof_connections_part1: NOF_CONNECTIONS_PART1,
+        })
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Playground {
+            nof_connections_part1: NOF_CONNECTIONS_PART1_EXAMPLE,
+            ..Self::parse(input)?
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let nof_connections = input.connections.len().min(input.nof_connections_part1);
+        part1(&create_circuits(
+            input.positions.len(),
+            &input.connections[..nof_connections],
+        ))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(&input.connections, &input.positions)
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = include_str!("../fixtures/day08.txt");
+
+    let positions = parse_input(input).unwrap();
+    let distances = compute_distances(&positions);
+    let connections: Vec<_> = distances
+        .iter()
+        .map(|(jb1, jb2, _)| (*jb1, *jb2))
+        .take(10)
+        .collect();
+    let circuits = create_circuits(positions.len(), &connections);
+
+    assert_eq!(part1(&circuits), 40);
+}
+
+#[test]
+fn test_part2() {
+    let input = include_str!("../fixtures/day08.txt");
+
+    let positions = parse_input(input).unwrap();
+    let distances = compute_distances(&positions);
+    let connections: Vec<_> = distances.iter().map(|(jb1, jb2, _)| (*jb1, *jb2)).collect();
+
+    assert_eq!(part2(&connections, &positions), 25272);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("1,2,-3\n4,5,6\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.token.as_str()),
+        (1, 5, "-3")
+    );
+    assert_eq!(error.message, "expected a non-negative integer");
+    assert!(parse_input("1,2,9223372036854775808\n").is_err());
+}
+
+#[test]
+fn test_large_coordinates() {
+    let positions = parse_input("0,0,9223372036854775807\n9223372036854775807,0,0\n").unwrap();
+    let distances = compute_distances(&positions);
+    assert_eq!(distances, [(0, 1, 2 * (i64::MAX as u128).pow(2))]);
+    assert_eq!(part2(&[(0, 1)], &positions), 0);
+}
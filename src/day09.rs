@@ -0,0 +1,86 @@
+use crate::{
+    ParseError, Solution, Unsolved,
+    geometry::{Point2, Rect},
+    parse::Source,
+};
+
+pub type Tile = Point2;
+
+pub fn parse_input(input: &str) -> Result<Vec<Tile>, ParseError> {
+    let source = Source::new(Day09::DAY, input);
+
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (x, y) = source.split_once(line, ',')?;
+
+            Ok(Point2::new(
+                source.non_negative_i64(x)?,
+                source.non_negative_i64(y)?,
+            ))
+        })
+        .collect()
+}
+
+/// The number of tiles in the rectangle with the two tiles as opposite corners.
+pub fn rectangle_area(tile1: Tile, tile2: Tile) -> u128 {
+    Rect::spanning(tile1, tile2).area()
+}
+
+pub fn part1(tiles: &[Tile]) -> u128 {
+    let mut max_area = 0;
+
+    for (idx, t1) in tiles.iter().enumerate() {
+        for t2 in tiles.iter().skip(idx + 1) {
+            max_area = max_area.max(rectangle_area(*t1, *t2));
+        }
+    }
+
+    max_area
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Tile>;
+    type Part1 = u128;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        Unsolved
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = include_str!("../fixtures/day09.txt");
+    let tiles = parse_input(input).unwrap();
+    assert_eq!(part1(&tiles), 50);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("7,1\n-11,1\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.token.as_str()),
+        (2, 1, "-11")
+    );
+    assert_eq!(error.message, "expected a non-negative integer");
+}
+
+#[test]
+fn test_large_coordinates() {
+    let tiles = parse_input("0,9223372036854775807\n9223372036854775807,0\n").unwrap();
+    assert_eq!(part1(&tiles), 1 << 126);
+}
@@ -0,0 +1,181 @@
+use std::str::FromStr;
+
+use crate::{
+    Maybe, ParseError, Solution, Unsolved, bitset::BitSet, parallel, parse::Source, search::bfs,
+};
+
+// lights and buttons are stored as bit sets of the light indices
+pub struct Machine {
+    pub target_lights: BitSet,
+    pub buttons: Vec<BitSet>,
+    pub joltage_levels: Vec<u64>,
+}
+
+/// Errors are positioned relative to the parsed string, i.e. always on line 1.
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let source = Source::new(Day10::DAY, input);
+
+        let mut target_lights = None;
+        let mut nof_lights = 0;
+        let mut button_tokens = Vec::new();
+        let mut joltage_levels = Vec::new();
+
+        for p in input.split_whitespace() {
+            if p.starts_with('[') {
+                target_lights = Some(build_target_lights(&source, p)?);
+                nof_lights = source.delimited(p, '[', ']')?.chars().count();
+            } else if p.starts_with('(') {
+                // the buttons are checked against the number of lights once the diagram is known
+                button_tokens.push(p);
+            } else if p.starts_with('{') {
+                joltage_levels = build_joltage_levels(&source, p)?;
+            } else {
+                return Err(source.error(p, "expected `[lights]`, `(button)` or `{joltages}`"));
+            }
+        }
+
+        let target_lights = target_lights
+            .ok_or_else(|| source.error(input.trim(), "expected a `[lights]` diagram"))?;
+        let buttons = button_tokens
+            .into_iter()
+            .map(|p| build_button(&source, p, nof_lights))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            target_lights,
+            buttons,
+            joltage_levels,
+        })
+    }
+}
+
+pub fn build_target_lights(source: &Source, s: &str) -> Result<BitSet, ParseError> {
+    BitSet::parse_diagram(source, s)
+}
+
+pub fn build_button(source: &Source, s: &str, nof_lights: usize) -> Result<BitSet, ParseError> {
+    BitSet::parse_indices(source, s, nof_lights)
+}
+
+pub fn build_joltage_levels(source: &Source, s: &str) -> Result<Vec<u64>, ParseError> {
+    let s = source.delimited(s, '{', '}')?;
+
+    s.split(',')
+        .map(|joltage_level| source.number(joltage_level))
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            line.parse().map_err(|error| ParseError {
+                line: idx + 1,
+                ..error
+            })
+        })
+        .collect()
+}
+
+/// `None` if the target lights cannot be reached with the buttons.
+pub fn min_number_of_button_presses(m: &Machine) -> Option<usize> {
+    bfs(
+        BitSet::new(),
+        |lights| m.buttons.iter().map(|b| lights ^ b).collect::<Vec<_>>(),
+        |lights| *lights == m.target_lights,
+    )
+    .cost()
+}
+
+/// `None` if any machine cannot be configured.
+pub fn part1(machines: &[Machine]) -> Option<usize> {
+    parallel::sum(machines, min_number_of_button_presses)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+    type Part1 = Maybe<usize>;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        Maybe(part1(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        Unsolved
+    }
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        min_number_of_button_presses(
+            &Machine::from_str("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap()
+        ),
+        Some(2)
+    );
+
+    assert_eq!(
+        min_number_of_button_presses(
+            &Machine::from_str("[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}")
+                .unwrap()
+        ),
+        Some(3)
+    );
+
+    assert_eq!(
+        min_number_of_button_presses(
+            &Machine::from_str("[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}")
+                .unwrap()
+        ),
+        Some(2)
+    );
+
+    assert_eq!(
+        min_number_of_button_presses(&Machine::from_str(
+            " [.#...#.#..] (3,5) (1,2,3,4,6,8,9) (0,4,8,9) (2,3,4,5,6,7,8,9) (6,8) (1,4,5,7,8) (1,2,5,9) (0,1,3,4,5,6,9) {5,22,17,25,34,32,38,24,45,22}"
+        )
+        .unwrap()),
+        Some(2)
+    );
+
+    assert_eq!(
+        min_number_of_button_presses(&Machine::from_str("[##] (0) (0)").unwrap()),
+        None
+    );
+    assert_eq!(
+        min_number_of_button_presses(&Machine::from_str("[..] (0)").unwrap()),
+        Some(0)
+    );
+
+    let input = include_str!("../fixtures/day10.txt");
+    let machines = parse_input(input).unwrap();
+    assert_eq!(part1(&machines), Some(7));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("[.##.] (3) {3}\n\n[...#.] (0,2,x) {7}\n")
+        .err()
+        .unwrap();
+    assert_eq!((error.day, error.line, error.column), (10, 3, 14));
+    assert_eq!(error.token, "x");
+
+    let error = Machine::from_str("[.##.] (0) (1,64)").err().unwrap();
+    assert_eq!((error.column, error.token.as_str()), (15, "64"));
+    assert_eq!(error.message, "expected a light index below 4");
+    assert!(Machine::from_str("(0,1) {3}").is_err());
+}
@@ -0,0 +1,138 @@
+use crate::{
+    ParseError, Solution,
+    graph::{Graph, GraphBuilder, NodeId},
+    memo::Memo,
+    parse::Source,
+};
+
+pub fn parse_input(input: &str) -> Result<Graph, ParseError> {
+    let source = Source::new(Day11::DAY, input);
+
+    let mut builder = GraphBuilder::new();
+
+    for line in input.lines().filter(|l| !l.is_empty()) {
+        let mut device = 0;
+        for (idx, part) in line.split_ascii_whitespace().enumerate() {
+            if (idx == 0) != part.ends_with(':') {
+                return Err(source.error(part, "expected `device:` followed by its outputs"));
+            }
+
+            if let Some(name) = part.strip_suffix(':') {
+                device = builder.node(name);
+            } else {
+                let output = builder.node(part);
+                builder.edge(device, output);
+            }
+        }
+    }
+
+    Ok(builder.build())
+}
+
+pub fn part1(devices: &Graph) -> u64 {
+    let (Some(you), Some(out)) = (devices.id("you"), devices.id("out")) else {
+        return 0;
+    };
+
+    let mut nof_paths_to_out = 0;
+
+    let mut open_nodes = vec![you];
+
+    while let Some(node) = open_nodes.pop() {
+        for &connected_node in devices.successors(node) {
+            if connected_node == out {
+                nof_paths_to_out += 1;
+            } else {
+                open_nodes.push(connected_node);
+            }
+        }
+    }
+
+    nof_paths_to_out
+}
+
+/// The IDs of the devices that the paths of part 2 end at and pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Devices {
+    pub out: NodeId,
+    pub fft: NodeId,
+    pub dac: NodeId,
+}
+
+/// The number of paths from `node` to `out`, counting only the ones that pass both `fft` and `dac`
+/// if they have not been seen yet.
+pub fn cached_dfs(
+    node: (NodeId, bool, bool),
+    cache: &mut Memo<(NodeId, bool, bool), u64>,
+    devices: &Graph,
+    ids: Devices,
+) -> u64 {
+    cache.get(node, &|&(node, has_seen_fft, has_seen_dac), nof_paths| {
+        let mut result = 0;
+
+        for &connected_node in devices.successors(node) {
+            if connected_node == ids.out {
+                if has_seen_fft && has_seen_dac {
+                    result += 1;
+                }
+            } else {
+                let has_seen_fft = has_seen_fft || connected_node == ids.fft;
+                let has_seen_dac = has_seen_dac || connected_node == ids.dac;
+                result += nof_paths((connected_node, has_seen_fft, has_seen_dac));
+            }
+        }
+
+        result
+    })
+}
+
+pub fn part2(devices: &Graph) -> u64 {
+    let id = |name| devices.id(name);
+    let (Some(svr), Some(out), Some(fft), Some(dac)) = (id("svr"), id("out"), id("fft"), id("dac"))
+    else {
+        return 0;
+    };
+
+    let ids = Devices { out, fft, dac };
+    cached_dfs((svr, false, false), &mut Memo::new(), devices, ids)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Graph;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = include_str!("../fixtures/day11-part1.txt");
+
+    let devices = parse_input(input).unwrap();
+
+    assert_eq!(part1(&devices), 5);
+}
+
+#[test]
+fn test_part2() {
+    let input = include_str!("../fixtures/day11-part2.txt");
+
+    let devices = parse_input(input).unwrap();
+
+    assert_eq!(part2(&devices), 2);
+}
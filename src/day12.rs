@@ -0,0 +1,96 @@
+use crate::{ParseError, Solution, Unsolved, parallel, parse::Source};
+
+pub struct TreeArea {
+    pub width: u64,
+    pub height: u64,
+    pub presents: Vec<u64>,
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<u64>, Vec<TreeArea>), ParseError> {
+    let source = Source::new(Day12::DAY, input);
+
+    let mut present_sizes = Vec::new();
+    let mut tree_areas = Vec::new();
+
+    for part in input.split("\n\n") {
+        if part.contains('#') {
+            // its a present
+            present_sizes.push(part.chars().filter(|c| *c == '#').count() as u64);
+        } else {
+            // its the part with the tree areas
+            for line in part.trim().lines() {
+                let (size, presents_list) = source.split_once(line, ':')?;
+                let (width, height) = source.split_once(size, 'x')?;
+                let width = source.number(width)?;
+                let height = source.number(height)?;
+                let presents: Vec<_> = presents_list
+                    .split_whitespace()
+                    .map(|p| source.number(p))
+                    .collect::<Result<_, _>>()?;
+
+                if presents.len() > present_sizes.len() {
+                    return Err(source.error(
+                        presents_list,
+                        format!("expected at most {} present counts", present_sizes.len()),
+                    ));
+                }
+
+                tree_areas.push(TreeArea {
+                    width,
+                    height,
+                    presents,
+                });
+            }
+        }
+    }
+
+    Ok((present_sizes, tree_areas))
+}
+
+pub fn part1(present_sizes: &[u64], tree_areas: &[TreeArea]) -> u64 {
+    parallel::sum(tree_areas, |tree_area| {
+        let total_present_size: u64 = tree_area
+            .presents
+            .iter()
+            .enumerate()
+            .map(|(idx, amount)| present_sizes[idx] * amount)
+            .sum();
+
+        u64::from(total_present_size <= tree_area.width * tree_area.height)
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = (Vec<u64>, Vec<TreeArea>);
+    type Part1 = u64;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        Unsolved
+    }
+}
+
+#[test]
+fn test_part1() {
+    let (present_sizes, tree_areas) = parse_input(include_str!("../fixtures/day12.txt")).unwrap();
+
+    assert_eq!(present_sizes, vec![7; 6]);
+    assert_eq!(tree_areas.len(), 3);
+    assert_eq!((tree_areas[1].width, tree_areas[1].height), (12, 5));
+    assert_eq!(tree_areas[2].presents, vec![1, 0, 1, 0, 3, 2]);
+
+    // the third region has enough space but the presents don't fit, which part 1 doesn't check
+    assert_eq!(part1(&present_sizes, &tree_areas[..2]), 2);
+}
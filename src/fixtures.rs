@@ -0,0 +1,173 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    answers::{Check, selected_parts},
+    runner::{Config, DayResult, Part},
+};
+
+#[cfg(test)]
+use crate::answers::Verdict;
+
+/// The puzzle examples shipped with the repository. Every `dayNN*.txt` file in it is an example
+/// for day NN, `expected.txt` lists their answers as `fixture part answer`.
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+pub struct Example {
+    pub day: u8,
+    pub path: PathBuf,
+    /// Only these parts are solved, both parts if it is empty.
+    pub expected: Vec<(Part, String)>,
+}
+
+impl Example {
+    pub fn config(&self) -> Config {
+        let part = match self.expected.as_slice() {
+            [(part, _)] => Some(*part),
+            _ => None,
+        };
+
+        Config {
+            part,
+            example: true,
+            parallel: false,
+        }
+    }
+
+    /// Checks the answers of the `selected` parts, an expected part that gave no answer is
+    /// `Verdict::Missing`.
+    pub fn check(&self, result: &DayResult, selected: Option<Part>) -> Vec<Check> {
+        selected_parts(selected)
+            .filter_map(|part| {
+                let expected = self.expected.iter().find(|(p, _)| *p == part);
+                Check::new(result, part, expected.map(|(_, e)| e.as_str()))
+            })
+            .collect()
+    }
+}
+
+fn fixture_day(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?.get(..2)?.parse().ok()
+}
+
+/// All examples in `dir` sorted by file name, restricted to `day` if given.
+pub fn examples(dir: &Path, day: Option<u8>) -> Result<Vec<Example>, String> {
+    let expected_path = dir.join("expected.txt");
+    let expected = std::fs::read_to_string(&expected_path)
+        .map_err(|error| format!("cannot read {}: {error}", expected_path.display()))?;
+
+    let mut examples = Vec::new();
+
+    let entries = std::fs::read_dir(dir)
+        .map_err(|error| format!("cannot read {}: {error}", dir.display()))?;
+    for entry in entries {
+        let path = entry.map_err(|error| error.to_string())?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let Some(fixture_day) = fixture_day(name) else {
+            continue;
+        };
+        if day.is_some_and(|day| day != fixture_day) || !name.ends_with(".txt") {
+            continue;
+        }
+
+        examples.push(Example {
+            day: fixture_day,
+            path: path.clone(),
+            expected: Vec::new(),
+        });
+    }
+
+    for (idx, line) in expected.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = || {
+            format!(
+                "{}, line {}: expected `fixture part answer`",
+                expected_path.display(),
+                idx + 1
+            )
+        };
+
+        let mut fields = line.split_whitespace();
+        let (Some(name), Some(part), Some(answer), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(error());
+        };
+        let part = match part {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return Err(error()),
+        };
+
+        if let Some(example) = examples.iter_mut().find(|e| e.path.ends_with(name)) {
+            example.expected.push((part, answer.to_string()));
+        } else if day.is_none() {
+            return Err(format!("{}: no fixture `{name}`", expected_path.display()));
+        }
+    }
+
+    examples.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(examples)
+}
+
+#[test]
+fn test_examples() {
+    let examples = examples(&fixtures_dir(), None).unwrap();
+
+    for day in 1..=crate::runner::DAYS.len() as u8 {
+        assert!(
+            examples.iter().any(|e| e.day == day),
+            "day {day} has no example"
+        );
+    }
+
+    for example in examples.iter().filter(|e| !e.expected.is_empty()) {
+        let input = std::fs::read_to_string(&example.path).unwrap();
+        let solve = crate::runner::solver(example.day).unwrap();
+        let result = solve(&input, &example.config()).unwrap();
+
+        for check in example.check(&result, example.config().part) {
+            assert_eq!(
+                check.verdict,
+                Verdict::Correct,
+                "{} part {}",
+                example.path.display(),
+                check.part.number()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_check_missing_answer() {
+    let example = examples(&fixtures_dir(), Some(1)).unwrap().remove(0);
+    let input = std::fs::read_to_string(&example.path).unwrap();
+    let config = Config {
+        part: Some(Part::One),
+        ..example.config()
+    };
+    let result = crate::runner::solve::<crate::day01::Day01>(&input, &config).unwrap();
+
+    let verdicts: Vec<_> = example
+        .check(&result, None)
+        .into_iter()
+        .map(|check| check.verdict)
+        .collect();
+    assert_eq!(
+        verdicts,
+        [
+            Verdict::Correct,
+            Verdict::Missing {
+                expected: "6".to_string()
+            }
+        ]
+    );
+    assert_eq!(example.check(&result, Some(Part::One)).len(), 1);
+}
@@ -0,0 +1,433 @@
+use std::{collections::BTreeMap, fmt::Write, ops::RangeInclusive};
+
+use crate::rng::Rng;
+
+/// Writes a random puzzle input of about `size` items, e.g. rotations, ranges or grid rows.
+pub type Generator = fn(usize, &mut Rng) -> String;
+
+/// The generator of every day and the size that is about the one of the puzzle inputs.
+pub const GENERATORS: [(Generator, usize); 12] = [
+    (day01, 4000),
+    (day02, 40),
+    (day03, 200),
+    (day04, 140),
+    (day05, 180),
+    (day06, 1000),
+    (day07, 142),
+    (day08, 1000),
+    (day09, 500),
+    (day10, 180),
+    (day11, 600),
+    (day12, 1000),
+];
+
+pub fn generator(day: u8) -> Option<(Generator, usize)> {
+    GENERATORS.get(usize::from(day).checked_sub(1)?).copied()
+}
+
+/// A random number with a number of `digits` in the range, every one of them is equally likely.
+fn number_with_digits(rng: &mut Rng, digits: RangeInclusive<u32>) -> u64 {
+    let (min, max) = digits.into_inner();
+    let digits = rng.range(u64::from(min)..=u64::from(max)) as u32;
+    rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+}
+
+/// `size` rotations of the dial like `L68`.
+fn day01(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let direction = rng.choose(&['L', 'R']);
+        writeln!(input, "{direction}{}", rng.range(1..=999)).unwrap();
+    }
+    input
+}
+
+/// `size` disjoint ID ranges like `11-22` on a single line. Like in the puzzle inputs, IDs have 2
+/// to 10 digits and the end of a range has at most one digit more than its start.
+fn day02(size: usize, rng: &mut Rng) -> String {
+    // the end of every range by its start
+    let mut ranges = BTreeMap::new();
+
+    while ranges.len() < size.max(1) {
+        let start = number_with_digits(rng, 2..=10);
+        let end = start + rng.range(0..=start.min(10_000));
+
+        // like in the puzzle inputs, ranges neither overlap nor touch
+        let after_previous = ranges
+            .range(..=start)
+            .next_back()
+            .is_none_or(|(_, &previous_end)| previous_end + 1 < start);
+        let before_next = ranges
+            .range(start..)
+            .next()
+            .is_none_or(|(&next_start, _)| end + 1 < next_start);
+        if after_previous && before_next {
+            ranges.insert(start, end);
+        }
+    }
+
+    let mut ranges: Vec<_> = ranges
+        .iter()
+        .map(|(start, end)| format!("{start}-{end}"))
+        .collect();
+    rng.shuffle(&mut ranges);
+
+    ranges.join(",") + "\n"
+}
+
+/// `size` banks of 100 batteries with joltages 1 to 9.
+fn day03(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        input.extend((0..100).map(|_| char::from(b'0' + rng.range(1..=9) as u8)));
+        input.push('\n');
+    }
+    input
+}
+
+/// A square grid of `size` rows, most cells hold a roll of paper `@`.
+fn day04(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        input.extend((0..size.max(1)).map(|_| if rng.chance(65) { '@' } else { '.' }));
+        input.push('\n');
+    }
+    input
+}
+
+/// `size` fresh ID ranges, which may overlap, followed by `size` available IDs, about half of
+/// them fresh.
+fn day05(size: usize, rng: &mut Rng) -> String {
+    const MAX_ID: u64 = 500_000_000_000_000;
+
+    let ranges: Vec<_> = (0..size)
+        .map(|_| {
+            let start = rng.range(1..=MAX_ID);
+            (start, start + rng.range(0..=MAX_ID / 1000))
+        })
+        .collect();
+
+    let mut input = String::new();
+    for (start, end) in &ranges {
+        writeln!(input, "{start}-{end}").unwrap();
+    }
+    input.push('\n');
+
+    for _ in 0..size {
+        let id = if ranges.is_empty() || rng.chance(50) {
+            rng.range(1..=MAX_ID)
+        } else {
+            let (start, end) = *rng.choose(&ranges);
+            rng.range(start..=end)
+        };
+        writeln!(input, "{id}").unwrap();
+    }
+    input
+}
+
+/// A worksheet of `size` problems with four numbers each, written in columns that are aligned
+/// left or right. Numbers have up to 3 digits, so that the answers fit for millions of problems.
+fn day06(size: usize, rng: &mut Rng) -> String {
+    const ROWS: usize = 4;
+
+    let mut lines = vec![String::new(); ROWS + 1];
+
+    for problem in 0..size.max(1) {
+        let numbers: Vec<String> = (0..ROWS)
+            .map(|_| number_with_digits(rng, 1..=3).to_string())
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let align_left = rng.chance(50);
+
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if problem > 0 {
+                line.push(' ');
+            }
+            if align_left {
+                write!(line, "{number:<width$}").unwrap();
+            } else {
+                write!(line, "{number:>width$}").unwrap();
+            }
+        }
+
+        let operator_line = &mut lines[ROWS];
+        if problem > 0 {
+            operator_line.push(' ');
+        }
+        write!(operator_line, "{:<width$}", rng.choose(&['+', '*'])).unwrap();
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// A manifold of `size` rows with the start in the middle of the top row. The splitters are in a
+/// triangle below the start like in the puzzle inputs, with some of them missing. There are at
+/// most 60 rows of splitters, so that the number of timelines fits into an `u64`.
+fn day07(size: usize, rng: &mut Rng) -> String {
+    const MAX_SPLITTER_ROWS: usize = 60;
+
+    let (width, height) = (size.max(1), size.max(1));
+    let middle = width / 2;
+    let spacing = 2 * (height / 2).div_ceil(MAX_SPLITTER_ROWS).max(1);
+
+    let mut input = String::new();
+    for y in 0..height {
+        let splitter_row = y / spacing;
+        for x in 0..width {
+            let c = if y == 0 && x == middle {
+                'S'
+            } else if y > 0 && y % spacing == 0 {
+                // the beams reach every second position of the triangle below the start
+                let offset = x.abs_diff(middle);
+                let in_triangle = offset < splitter_row && (splitter_row - 1 - offset) % 2 == 0;
+                if in_triangle && rng.chance(80) {
+                    '^'
+                } else {
+                    '.'
+                }
+            } else {
+                '.'
+            };
+            input.push(c);
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// `size` junction boxes at random positions, at least two of them.
+fn day08(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(2) {
+        let [x, y, z] = [(); 3].map(|_| rng.range(0..=99_999));
+        writeln!(input, "{x},{y},{z}").unwrap();
+    }
+    input
+}
+
+/// About `size` red tiles that are the corners of an x-monotone polygon of horizontal and vertical
+/// edges, like the puzzle inputs. It is a sequence of columns that each span the middle row.
+fn day09(size: usize, rng: &mut Rng) -> String {
+    const MAX_COORDINATE: u64 = 100_000;
+    const MIDDLE: u64 = MAX_COORDINATE / 2;
+
+    let nof_columns = (size / 4).max(1);
+    let max_step = MAX_COORDINATE / (nof_columns as u64 + 1);
+
+    let mut xs = vec![rng.range(0..=max_step)];
+    for _ in 0..nof_columns {
+        xs.push(xs[xs.len() - 1] + rng.range(1..=max_step.max(1)));
+    }
+
+    // adjacent columns differ in height, otherwise their corners would not be corners
+    let mut tops: Vec<u64> = Vec::new();
+    let mut bottoms: Vec<u64> = Vec::new();
+    for _ in 0..nof_columns {
+        let top = loop {
+            let top = rng.range(MIDDLE + 1..=MAX_COORDINATE);
+            if tops.last() != Some(&top) {
+                break top;
+            }
+        };
+        let bottom = loop {
+            let bottom = rng.range(0..=MIDDLE - 1);
+            if bottoms.last() != Some(&bottom) {
+                break bottom;
+            }
+        };
+        tops.push(top);
+        bottoms.push(bottom);
+    }
+
+    let mut tiles = Vec::new();
+    // the upper edge from left to right
+    for (idx, &top) in tops.iter().enumerate() {
+        tiles.push((xs[idx], top));
+        tiles.push((xs[idx + 1], top));
+    }
+    // the lower edge from right to left
+    for (idx, &bottom) in bottoms.iter().enumerate().rev() {
+        tiles.push((xs[idx + 1], bottom));
+        tiles.push((xs[idx], bottom));
+    }
+
+    let mut input = String::new();
+    for (x, y) in tiles {
+        writeln!(input, "{x},{y}").unwrap();
+    }
+    input
+}
+
+/// `size` machines like `[.##.] (3) (1,3) {3,5,4,7}`. The lights can always be reached, and the
+/// joltages are the result of pressing every button a random number of times.
+fn day10(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let nof_lights = rng.range(4..=10) as usize;
+        let nof_buttons = rng.range(nof_lights as u64 / 2 + 1..=nof_lights as u64 + 3);
+
+        let mut lights = vec![false; nof_lights];
+        let mut joltages = vec![0; nof_lights];
+        let mut buttons = Vec::new();
+
+        for _ in 0..nof_buttons {
+            let mut indices: Vec<usize> = (0..nof_lights).collect();
+            rng.shuffle(&mut indices);
+            indices.truncate(rng.range(1..=nof_lights as u64 - 1) as usize);
+            indices.sort_unstable();
+
+            let toggled = rng.chance(50);
+            let presses = rng.range(0..=20);
+            for &idx in &indices {
+                lights[idx] ^= toggled;
+                joltages[idx] += presses;
+            }
+
+            let indices: Vec<_> = indices.iter().map(usize::to_string).collect();
+            buttons.push(format!("({})", indices.join(",")));
+        }
+
+        let lights: String = lights
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect();
+        let joltages: Vec<_> = joltages.iter().map(u64::to_string).collect();
+        writeln!(
+            input,
+            "[{lights}] {} {{{}}}",
+            buttons.join(" "),
+            joltages.join(",")
+        )
+        .unwrap();
+    }
+    input
+}
+
+/// About `size` devices in layers, every one has outputs to one or two devices of the next layer
+/// and the last layer to `out`. `svr` is the only device of the first layer, `fft` and `dac` are
+/// on a path from it to `out`, and `you` is a few layers before `out`. There are at most 48
+/// layers, so that the number of paths fits into an `u64`.
+fn day11(size: usize, rng: &mut Rng) -> String {
+    const MAX_LAYERS: usize = 48;
+    const RESERVED: [&str; 5] = ["svr", "fft", "dac", "you", "out"];
+
+    let nof_layers = (size / 8).clamp(8, MAX_LAYERS);
+    let width = (size / nof_layers).max(1);
+
+    // three or more letters for every device, skipping the special ones
+    let mut names = (0..).map(|mut idx: usize| {
+        let mut name = Vec::new();
+        while name.len() < 3 || idx > 0 {
+            name.push(b'a' + (idx % 26) as u8);
+            idx /= 26;
+        }
+        String::from_utf8(name).expect("names are ascii")
+    });
+    let mut next_name = || loop {
+        let name = names.next().expect("there are infinitely many names");
+        if !RESERVED.contains(&name.as_str()) {
+            break name;
+        }
+    };
+
+    let mut layers = vec![vec!["svr".to_string()]];
+    for _ in 1..nof_layers {
+        layers.push((0..width).map(|_| next_name()).collect());
+    }
+    layers.push(vec!["out".to_string()]);
+
+    // a path from `svr` over `fft` and `dac` to `out`, so that part 2 has paths to count
+    let path: Vec<usize> = layers.iter().map(|layer| rng.index(layer.len())).collect();
+    for (layer, name) in [(nof_layers / 4, "fft"), (nof_layers / 2, "dac")] {
+        layers[layer][path[layer]] = name.to_string();
+    }
+    layers[nof_layers - 3][rng.index(width)] = "you".to_string();
+
+    let mut lines = Vec::new();
+    for (idx, (layer, next_layer)) in layers.iter().zip(&layers[1..]).enumerate() {
+        for (device_idx, device) in layer.iter().enumerate() {
+            let mut outputs = next_layer.clone();
+            rng.shuffle(&mut outputs);
+            outputs.truncate(rng.range(1..=2) as usize);
+
+            let next_on_path = &next_layer[path[idx + 1]];
+            if device_idx == path[idx] && !outputs.contains(next_on_path) {
+                outputs[0] = next_on_path.clone();
+            }
+            lines.push(format!("{device}: {}", outputs.join(" ")));
+        }
+    }
+    rng.shuffle(&mut lines);
+
+    lines.join("\n") + "\n"
+}
+
+/// Six random presents of 3x3 cells followed by `size` tree areas. The presents of an area cover
+/// between 70% and 110% of it, so that some fit and some don't.
+fn day12(size: usize, rng: &mut Rng) -> String {
+    const NOF_PRESENTS: usize = 6;
+
+    let mut input = String::new();
+    let mut present_sizes = Vec::new();
+
+    for present in 0..NOF_PRESENTS {
+        let mut cells = [false; 9];
+        for cell in cells.iter_mut().take(rng.range(5..=7) as usize) {
+            *cell = true;
+        }
+        rng.shuffle(&mut cells);
+        present_sizes.push(cells.iter().filter(|&&c| c).count() as u64);
+
+        writeln!(input, "{present}:").unwrap();
+        for row in cells.chunks(3) {
+            let row: String = row.iter().map(|&c| if c { '#' } else { '.' }).collect();
+            writeln!(input, "{row}").unwrap();
+        }
+        input.push('\n');
+    }
+
+    for _ in 0..size {
+        let (width, height) = (rng.range(35..=50), rng.range(35..=50));
+        let mut budget = width * height * rng.range(70..=110) / 100;
+
+        let mut counts = [0; NOF_PRESENTS];
+        loop {
+            let present = rng.index(NOF_PRESENTS);
+            if present_sizes[present] > budget {
+                break;
+            }
+            budget -= present_sizes[present];
+            counts[present] += 1;
+        }
+
+        let counts: Vec<_> = counts.iter().map(u64::to_string).collect();
+        writeln!(input, "{width}x{height}: {}", counts.join(" ")).unwrap();
+    }
+    input
+}
+
+#[test]
+fn test_generate() {
+    use crate::runner::{Config, solver};
+
+    for day in 1..=12 {
+        let (generate, default_size) = generator(day).unwrap();
+        assert_eq!(
+            generate(20, &mut Rng::new(1)),
+            generate(20, &mut Rng::new(1))
+        );
+        assert_ne!(
+            generate(20, &mut Rng::new(1)),
+            generate(20, &mut Rng::new(2))
+        );
+
+        for size in [0, 1, 7, default_size] {
+            let input = generate(size, &mut Rng::new(size as u64));
+            let result = solver(day).unwrap()(&input, &Config::default());
+            assert!(result.is_ok(), "day {day} size {size}: {input}");
+        }
+    }
+
+    assert!(generator(13).is_none());
+}
@@ -0,0 +1,397 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A point on the integer plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The difference between two `Point2`s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point in integer space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// The difference between two `Point3`s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The square of the Euclidean distance, which unlike the distance itself is exact.
+    pub fn euclidean_squared(self, other: Self) -> u128 {
+        (other - self).length_squared()
+    }
+
+    pub fn euclidean(self, other: Self) -> f64 {
+        (self.euclidean_squared(other) as f64).sqrt()
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        let v = other - self;
+        v.x.abs() + v.y.abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        let v = other - self;
+        v.x.abs().max(v.y.abs())
+    }
+}
+
+impl Vector2 {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn length_squared(self) -> u128 {
+        square(self.x) + square(self.y)
+    }
+
+    pub fn dot(self, other: Self) -> i128 {
+        wide(self.x) * wide(other.x) + wide(self.y) * wide(other.y)
+    }
+
+    /// The z component of the 3D cross product, positive if `other` is counterclockwise of `self`.
+    pub fn cross(self, other: Self) -> i128 {
+        wide(self.x) * wide(other.y) - wide(self.y) * wide(other.x)
+    }
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The square of the Euclidean distance, which unlike the distance itself is exact.
+    pub fn euclidean_squared(self, other: Self) -> u128 {
+        (other - self).length_squared()
+    }
+
+    pub fn euclidean(self, other: Self) -> f64 {
+        (self.euclidean_squared(other) as f64).sqrt()
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        let v = other - self;
+        v.x.abs() + v.y.abs() + v.z.abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        let v = other - self;
+        v.x.abs().max(v.y.abs()).max(v.z.abs())
+    }
+}
+
+impl Vector3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn length_squared(self) -> u128 {
+        square(self.x) + square(self.y) + square(self.z)
+    }
+
+    pub fn dot(self, other: Self) -> i128 {
+        wide(self.x) * wide(other.x) + wide(self.y) * wide(other.y) + wide(self.z) * wide(other.z)
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+// Products of coordinates are computed in 128 bits, squared lengths fit in them for any `i64`
+// coordinates and areas for any non-negative ones.
+fn wide(n: i64) -> i128 {
+    i128::from(n)
+}
+
+fn square(n: i64) -> u128 {
+    u128::from(n.unsigned_abs()).pow(2)
+}
+
+impl Sub for Point2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Self) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add<Vector2> for Point2 {
+    type Output = Self;
+
+    fn add(self, v: Vector2) -> Self {
+        Self::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl Sub<Vector2> for Point2 {
+    type Output = Self;
+
+    fn sub(self, v: Vector2) -> Self {
+        Self::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl Add for Vector2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Vector2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vector2 {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Self) -> Vector3 {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Add<Vector3> for Point3 {
+    type Output = Self;
+
+    fn add(self, v: Vector3) -> Self {
+        Self::new(self.x + v.x, self.y + v.y, self.z + v.z)
+    }
+}
+
+impl Sub<Vector3> for Point3 {
+    type Output = Self;
+
+    fn sub(self, v: Vector3) -> Self {
+        Self::new(self.x - v.x, self.y - v.y, self.z - v.z)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Vector3 {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+/// An axis-aligned rectangle of grid cells, `min` and `max` are both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Rect {
+    /// The smallest rectangle containing both corners.
+    pub fn spanning(a: Point2, b: Point2) -> Self {
+        Self {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    /// The number of cells.
+    pub fn area(&self) -> u128 {
+        let side = |min: i64, max: i64| u128::from(max.abs_diff(min)) + 1;
+        side(self.min.x, self.max.x) * side(self.min.y, self.max.y)
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let rect = Self {
+            min: Point2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Point2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        };
+        (rect.min.x <= rect.max.x && rect.min.y <= rect.max.y).then_some(rect)
+    }
+}
+
+/// A simple polygon given by its vertices in order, the last one connects back to the first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    pub vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2>) -> Self {
+        Self { vertices }
+    }
+
+    /// The edges as pairs of consecutive vertices, including the closing one.
+    pub fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    /// Twice the signed area by the shoelace formula, positive for counterclockwise vertices.
+    /// Unlike the area itself it is always an integer.
+    pub fn signed_double_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| Vector2::new(a.x, a.y).cross(Vector2::new(b.x, b.y)))
+            .sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.signed_double_area().abs() as f64 / 2.0
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.euclidean(b)).sum()
+    }
+
+    /// Whether `p` lies inside of the polygon or on its boundary.
+    pub fn contains(&self, p: Point2) -> bool {
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            let on_line = (b - a).cross(p - a) == 0;
+            if on_line && Rect::spanning(a, b).contains(p) {
+                return true;
+            }
+
+            // count the edges crossed by a ray from `p` in positive x direction
+            if (a.y > p.y) != (b.y > p.y) {
+                // the sign of the cross product tells on which side of the edge `p` lies
+                let side = (b - a).cross(p - a);
+                if (side > 0) == (b.y > a.y) {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+}
+
+#[test]
+fn test_geometry() {
+    let a = Point3::new(1, 2, 3);
+    let b = Point3::new(4, -2, 3);
+    assert_eq!(a.euclidean_squared(b), 25);
+    assert_eq!(a.euclidean(b), 5.0);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a + (b - a) * 2, Point3::new(7, -6, 3));
+    assert_eq!(
+        Vector3::new(1, 0, 0).cross(Vector3::new(0, 1, 0)),
+        Vector3::new(0, 0, 1)
+    );
+
+    let p = Point2::new(-1, 2);
+    assert_eq!(p.manhattan(Point2::new(2, -2)), 7);
+    assert_eq!(p.chebyshev(Point2::new(2, -2)), 4);
+
+    let rect = Rect::spanning(Point2::new(11, 1), Point2::new(2, 5));
+    assert_eq!((rect.width(), rect.height(), rect.area()), (10, 5, 50));
+    assert!(rect.contains(Point2::new(2, 1)) && !rect.contains(Point2::new(1, 1)));
+    assert_eq!(
+        rect.intersection(&Rect::spanning(Point2::new(0, 0), Point2::new(3, 3))),
+        Some(Rect::spanning(Point2::new(2, 1), Point2::new(3, 3)))
+    );
+
+    // an L shape
+    let polygon = Polygon::new(
+        [(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]
+            .map(|(x, y)| Point2::new(x, y))
+            .to_vec(),
+    );
+    assert_eq!(polygon.signed_double_area(), 24);
+    assert_eq!(polygon.area(), 12.0);
+    assert_eq!(polygon.perimeter(), 16.0);
+
+    let far = Point3::new(0, 0, i64::MAX);
+    assert_eq!(
+        far.euclidean_squared(Point3::new(0, 0, 0)),
+        (1 << 126) - (1 << 64) + 1
+    );
+    let rect = Rect::spanning(Point2::new(0, i64::MAX), Point2::new(i64::MAX, 0));
+    assert_eq!(rect.area(), 1 << 126);
+    assert!(polygon.contains(Point2::new(1, 1)));
+    assert!(polygon.contains(Point2::new(3, 2)));
+    assert!(polygon.contains(Point2::new(0, 3)));
+    assert!(!polygon.contains(Point2::new(3, 3)));
+    assert!(!polygon.contains(Point2::new(5, 0)));
+    assert!(!polygon.contains(Point2::new(-1, 2)));
+}
@@ -0,0 +1,233 @@
+use std::{collections::HashMap, ops::Range};
+
+/// Dense ID of a node, IDs are handed out in the order the nodes are first seen.
+pub type NodeId = usize;
+
+/// Collects labeled nodes and edges, see `Graph`.
+#[derive(Debug, Clone, Default)]
+pub struct GraphBuilder {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<(NodeId, NodeId)>,
+}
+
+impl GraphBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ID of the node called `name`, which is added if it is new.
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    pub fn edge(&mut self, from: NodeId, to: NodeId) {
+        self.edges.push((from, to));
+    }
+
+    pub fn build(self) -> Graph {
+        let nof_nodes = self.names.len();
+        let successors = Adjacency::new(nof_nodes, self.edges.iter().copied());
+        let predecessors = Adjacency::new(nof_nodes, self.edges.iter().map(|&(a, b)| (b, a)));
+
+        Graph {
+            names: self.names,
+            ids: self.ids,
+            successors,
+            predecessors,
+        }
+    }
+}
+
+/// The neighbors of all nodes in one vector, `offsets[id]..offsets[id + 1]` are the ones of `id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Adjacency {
+    offsets: Vec<usize>,
+    neighbors: Vec<NodeId>,
+}
+
+impl Adjacency {
+    fn new(nof_nodes: usize, edges: impl Iterator<Item = (NodeId, NodeId)> + Clone) -> Self {
+        let mut offsets = vec![0; nof_nodes + 1];
+        for (from, _) in edges.clone() {
+            offsets[from + 1] += 1;
+        }
+        for idx in 1..offsets.len() {
+            offsets[idx] += offsets[idx - 1];
+        }
+
+        let mut next = offsets.clone();
+        let mut neighbors = vec![0; offsets[nof_nodes]];
+        for (from, to) in edges {
+            neighbors[next[from]] = to;
+            next[from] += 1;
+        }
+
+        Self { offsets, neighbors }
+    }
+
+    fn get(&self, id: NodeId) -> &[NodeId] {
+        &self.neighbors[self.offsets[id]..self.offsets[id + 1]]
+    }
+}
+
+/// A directed graph with labeled nodes, stored as forward and reverse adjacency lists.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    successors: Adjacency,
+    predecessors: Adjacency,
+}
+
+impl Graph {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> Range<NodeId> {
+        0..self.names.len()
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    /// The targets of the edges starting at `id`, in the order they were added.
+    pub fn successors(&self, id: NodeId) -> &[NodeId] {
+        self.successors.get(id)
+    }
+
+    /// The sources of the edges ending at `id`.
+    pub fn predecessors(&self, id: NodeId) -> &[NodeId] {
+        self.predecessors.get(id)
+    }
+
+    /// Every node before all of its successors, `None` if the graph has a cycle.
+    pub fn topological_order(&self) -> Option<Vec<NodeId>> {
+        let mut in_degrees: Vec<_> = self.nodes().map(|id| self.predecessors(id).len()).collect();
+        let mut order: Vec<_> = self.nodes().filter(|&id| in_degrees[id] == 0).collect();
+
+        let mut idx = 0;
+        while let Some(&id) = order.get(idx) {
+            for &successor in self.successors(id) {
+                in_degrees[successor] -= 1;
+                if in_degrees[successor] == 0 {
+                    order.push(successor);
+                }
+            }
+            idx += 1;
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Which nodes can be reached from `start`, including `start` itself.
+    pub fn reachable_from(&self, start: NodeId) -> Vec<bool> {
+        self.flood(start, |id| self.successors(id))
+    }
+
+    /// Which nodes can reach `target`, including `target` itself.
+    pub fn reaching(&self, target: NodeId) -> Vec<bool> {
+        self.flood(target, |id| self.predecessors(id))
+    }
+
+    fn flood<'a>(&'a self, start: NodeId, neighbors: impl Fn(NodeId) -> &'a [NodeId]) -> Vec<bool> {
+        let mut visited = vec![false; self.len()];
+        visited[start] = true;
+        let mut open_nodes = vec![start];
+
+        while let Some(id) = open_nodes.pop() {
+            for &neighbor in neighbors(id) {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    open_nodes.push(neighbor);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// The graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let quoted = |id| {
+            format!(
+                "\"{}\"",
+                self.name(id).replace('\\', "\\\\").replace('"', "\\\"")
+            )
+        };
+
+        let mut dot = String::from("digraph {\n");
+        for id in self.nodes() {
+            dot.push_str(&format!("    {};\n", quoted(id)));
+        }
+        for id in self.nodes() {
+            for &successor in self.successors(id) {
+                dot.push_str(&format!("    {} -> {};\n", quoted(id), quoted(successor)));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[test]
+fn test_graph() {
+    let mut builder = GraphBuilder::new();
+    let a = builder.node("a");
+    let b = builder.node("b");
+    let c = builder.node("c");
+    assert_eq!(builder.node("a"), a);
+    builder.edge(a, b);
+    builder.edge(a, c);
+    builder.edge(c, b);
+    let d = builder.node("d\"");
+    let graph = builder.build();
+
+    assert_eq!(graph.len(), 4);
+    assert_eq!(graph.id("c"), Some(c));
+    assert_eq!(graph.name(b), "b");
+    assert_eq!(graph.successors(a), [b, c]);
+    assert_eq!(graph.predecessors(b), [a, c]);
+    assert!(graph.successors(d).is_empty());
+
+    assert_eq!(graph.topological_order(), Some(vec![a, d, c, b]));
+    assert_eq!(graph.reachable_from(c), [false, true, true, false]);
+    assert_eq!(graph.reaching(c), [true, false, true, false]);
+    assert_eq!(
+        graph.to_dot(),
+        r#"digraph {
+    "a";
+    "b";
+    "c";
+    "d\"";
+    "a" -> "b";
+    "a" -> "c";
+    "c" -> "b";
+}
+"#
+    );
+
+    let mut builder = GraphBuilder::new();
+    let a = builder.node("a");
+    let b = builder.node("b");
+    builder.edge(a, b);
+    builder.edge(b, a);
+    assert_eq!(builder.build().topological_order(), None);
+}
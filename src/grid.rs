@@ -0,0 +1,219 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{ParseError, parse::Source};
+
+/// A cell of a grid as `(x, y)`, `x` is the column and `y` the row counted from the top.
+pub type Position = (usize, usize);
+
+/// The four orthogonal directions as `(dx, dy)`.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The eight orthogonal and diagonal directions as `(dx, dy)`.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Parses a grid with one character per cell, empty lines are skipped. `parse_cell` returns
+    /// `None` for characters that are no valid cell, they are reported with `expected` as message.
+    pub fn parse(
+        source: &Source,
+        input: &str,
+        expected: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            for (idx, c) in line.char_indices() {
+                let cell = parse_cell(c)
+                    .ok_or_else(|| source.error(&line[idx..idx + c.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(source.error(
+                        line,
+                        format!("expected a row of {width} cells like the first one"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let Some(width) = width else {
+            return Err(source.error(input, "expected a grid"));
+        };
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.1 * self.width + position.0])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.1 * self.width + position.0])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// All positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell row by row that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// The position one step in direction `(dx, dy)`, `None` if it is outside of the grid.
+    pub fn step(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// The positions of the orthogonal neighbors inside of the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions of the orthogonal and diagonal neighbors inside of the grid.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+/// Prints every row on its own line, cells are not separated.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid() {
+    let input = "#..\n.#.\n\n";
+    let source = Source::new(0, input);
+    let parse_cell = |c| match c {
+        '#' => Some('#'),
+        '.' => Some('.'),
+        _ => None,
+    };
+
+    let grid = Grid::parse(&source, input, "expected `#` or `.`", parse_cell).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 1)], '#');
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.find(|&c| c == '#'), Some((0, 0)));
+    assert_eq!(grid.to_string(), "#..\n.#.\n");
+
+    assert_eq!(
+        grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbors8((2, 1)).count(), 3);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+
+    let input = "#..\n.x.\n";
+    let error = Grid::parse(&Source::new(0, input), input, "expected", parse_cell).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+
+    let input = "#..\n.#\n";
+    let error = Grid::parse(&Source::new(0, input), input, "expected", parse_cell).unwrap_err();
+    assert_eq!((error.line, error.token.as_str()), (2, ".#"));
+}
@@ -0,0 +1,168 @@
+use std::ops::RangeInclusive;
+
+/// A set of `u64` stored as sorted, disjoint and non-adjacent inclusive ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts the ranges and merges the ones that overlap or touch.
+    fn normalized(mut ranges: Vec<(u64, u64)>) -> Self {
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of disjoint ranges.
+    pub fn nof_ranges(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The number of values in the set. Overflows if the set covers all of `u64`.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|(start, end)| end - start + 1).sum()
+    }
+
+    /// The disjoint ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let idx = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        *self = self.union(&Self::from_iter([range]));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized([self.ranges.as_slice(), other.ranges.as_slice()].concat())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(&&(a_start, a_end)), Some(&&(b_start, b_end))) = (a.peek(), b.peek()) {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The values of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(0..=u64::MAX))
+    }
+
+    /// The values within `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<u64>) -> Self {
+        let (min, max) = bounds.into_inner();
+        let mut ranges = Vec::new();
+
+        // the first value that may still be missing, `None` once `max` is covered
+        let mut next = Some(min);
+        for &(start, end) in &self.ranges {
+            let Some(gap_start) = next.filter(|&n| n <= max) else {
+                break;
+            };
+            if start > gap_start {
+                ranges.push((gap_start, (start - 1).min(max)));
+            }
+            if end >= gap_start {
+                next = end.checked_add(1);
+            }
+        }
+        if let Some(gap_start) = next.filter(|&n| n <= max) {
+            ranges.push((gap_start, max));
+        }
+
+        Self { ranges }
+    }
+}
+
+/// Empty ranges are ignored.
+impl FromIterator<RangeInclusive<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(iter: I) -> Self {
+        Self::normalized(
+            iter.into_iter()
+                .filter(|range| !range.is_empty())
+                .map(RangeInclusive::into_inner)
+                .collect(),
+        )
+    }
+}
+
+#[test]
+fn test_interval_set() {
+    let set = IntervalSet::from_iter([10..=14, 3..=5, 16..=20, 12..=18, 6..=6]);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [3..=6, 10..=20]);
+    assert_eq!(set.len(), 15);
+    assert!(set.contains(3) && set.contains(6) && set.contains(20));
+    assert!(!set.contains(2) && !set.contains(7) && !set.contains(21));
+
+    let other = IntervalSet::from_iter([0..=4, 8..=12, 20..=u64::MAX]);
+    assert_eq!(
+        set.intersection(&other).iter().collect::<Vec<_>>(),
+        [3..=4, 10..=12, 20..=20]
+    );
+    assert_eq!(
+        set.difference(&other).iter().collect::<Vec<_>>(),
+        [5..=6, 13..=19]
+    );
+    assert_eq!(
+        set.union(&other).iter().collect::<Vec<_>>(),
+        [0..=6, 8..=u64::MAX]
+    );
+
+    assert_eq!(
+        set.complement(0..=25).iter().collect::<Vec<_>>(),
+        [0..=2, 7..=9, 21..=25]
+    );
+    assert_eq!(set.complement(4..=12).iter().collect::<Vec<_>>(), [7..=9]);
+    assert!(other.complement(30..=u64::MAX).is_empty());
+    assert_eq!(
+        IntervalSet::new()
+            .complement(1..=2)
+            .iter()
+            .collect::<Vec<_>>(),
+        [1..=2]
+    );
+
+    let mut set = set;
+    set.insert(7..=9);
+    assert_eq!(set.nof_ranges(), 1);
+}
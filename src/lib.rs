@@ -0,0 +1,36 @@
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod bitset;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod fixtures;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod interval_set;
+pub mod memo;
+pub mod memory;
+pub mod parallel;
+pub mod parse;
+pub mod report;
+pub mod rng;
+pub mod runner;
+pub mod search;
+mod solution;
+pub mod union_find;
+
+pub use parse::ParseError;
+pub use runner::run;
+pub use solution::{Answer, Maybe, Solution, Unsolved};
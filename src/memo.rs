@@ -0,0 +1,153 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// A cache for recursive functions, which counts how often a value was looked up and found.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lookups that found a cached value.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Lookups that had to compute the value.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// The number of cached values.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn cached(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// The value of `f` for `key`. `f` gets the key and a function to recurse with, which looks up
+    /// or computes the value of another key. Every recursion uses the call stack, see
+    /// `get_with_stack` for deep recursions.
+    pub fn get(&mut self, key: K, f: &impl Fn(&K, &mut dyn FnMut(K) -> V) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+
+        let value = f(&key, &mut |key| self.get(key, f));
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Like `get`, but uses an explicit stack instead of recursion, so that deep recursions don't
+    /// overflow the call stack. `dependencies` returns the keys whose values are needed for `key`,
+    /// `combine` computes its value from them, given in the same order.
+    ///
+    /// Panics if a key depends on itself.
+    pub fn get_with_stack(
+        &mut self,
+        key: K,
+        dependencies: impl Fn(&K) -> Vec<K>,
+        combine: impl Fn(&K, &[V]) -> V,
+    ) -> V {
+        // keys whose value is needed, with their dependencies once they have been looked at
+        let mut stack: Vec<(K, Option<Vec<K>>)> = vec![(key.clone(), None)];
+        let mut in_progress = HashSet::new();
+
+        while let Some((key, key_dependencies)) = stack.last_mut() {
+            if key_dependencies.is_none() && self.cache.contains_key(key) {
+                self.hits += 1;
+                stack.pop();
+                continue;
+            }
+
+            let Some(key_dependencies) = key_dependencies else {
+                let new_dependencies = dependencies(key);
+                in_progress.insert(key.clone());
+                *key_dependencies = Some(new_dependencies.clone());
+
+                for dependency in new_dependencies.into_iter().rev() {
+                    assert!(
+                        !in_progress.contains(&dependency),
+                        "memoized values must not depend on themselves"
+                    );
+                    stack.push((dependency, None));
+                }
+                continue;
+            };
+
+            let values: Vec<V> = key_dependencies
+                .iter()
+                .map(|dependency| self.cache[dependency].clone())
+                .collect();
+            let value = combine(key, &values);
+            self.misses += 1;
+
+            in_progress.remove(key);
+            self.cache.insert(key.clone(), value);
+            stack.pop();
+        }
+
+        self.cache[&key].clone()
+    }
+}
+
+#[test]
+fn test_memo() {
+    let fibonacci = |n: &u64, fib: &mut dyn FnMut(u64) -> u64| {
+        if *n < 2 { *n } else { fib(n - 1) + fib(n - 2) }
+    };
+
+    let mut memo = Memo::new();
+    assert_eq!(memo.get(50, &fibonacci), 12586269025);
+    assert_eq!((memo.misses(), memo.hits(), memo.len()), (51, 48, 51));
+    assert_eq!(memo.get(40, &fibonacci), 102334155);
+    assert_eq!(memo.hits(), 49);
+
+    let dependencies = |n: &u64| if *n < 2 { vec![] } else { vec![n - 1, n - 2] };
+    let combine = |n: &u64, values: &[u64]| if *n < 2 { *n } else { values[0] + values[1] };
+
+    let mut memo = Memo::new();
+    assert_eq!(memo.get_with_stack(50, dependencies, combine), 12586269025);
+    assert_eq!((memo.misses(), memo.hits(), memo.len()), (51, 48, 51));
+
+    // far deeper than the call stack allows
+    let mut memo = Memo::new();
+    let count_down = memo.get_with_stack(
+        1_000_000u64,
+        |&n| if n == 0 { vec![] } else { vec![n - 1] },
+        |_, values: &[u64]| values.first().map_or(0, |v| v + 1),
+    );
+    assert_eq!(count_down, 1_000_000);
+}
+
+#[test]
+#[should_panic = "must not depend on themselves"]
+fn test_memo_cycle() {
+    Memo::new().get_with_stack(0u8, |&n| vec![(n + 1) % 3], |_, _| 0);
+}
@@ -0,0 +1,177 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicU64, Ordering::Relaxed},
+};
+
+/// The allocations made during one phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// Number of allocations, a reallocation counts as a new allocation.
+    pub count: u64,
+    /// Bytes requested by all allocations.
+    pub bytes: u64,
+    /// Most bytes that were live at once, on top of the ones already live when the phase started.
+    pub peak: u64,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Wraps the system allocator and counts allocations, allocated bytes and live bytes.
+pub struct CountingAllocator {
+    count: AtomicU64,
+    bytes: AtomicU64,
+    live: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            live: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        }
+    }
+
+    fn allocated(&self, size: usize) {
+        let size = size as u64;
+        self.count.fetch_add(1, Relaxed);
+        self.bytes.fetch_add(size, Relaxed);
+        let live = self.live.fetch_add(size, Relaxed) + size;
+        self.peak.fetch_max(live, Relaxed);
+    }
+
+    fn deallocated(&self, size: usize) {
+        self.live.fetch_sub(size as u64, Relaxed);
+    }
+
+    /// Counts the allocations made while `f` runs. The counters are shared by all threads, so
+    /// allocations of other threads running at the same time are counted as well.
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Allocations) {
+        let count = self.count.load(Relaxed);
+        let bytes = self.bytes.load(Relaxed);
+        let live = self.live.load(Relaxed);
+        self.peak.store(live, Relaxed);
+
+        let result = f();
+
+        let allocations = Allocations {
+            count: self.count.load(Relaxed) - count,
+            bytes: self.bytes.load(Relaxed) - bytes,
+            peak: self.peak.load(Relaxed).saturating_sub(live),
+        };
+        (result, allocations)
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            self.allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            self.allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        self.deallocated(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            self.deallocated(layout.size());
+            self.allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Installed as the global allocator when the crate is built with the `count-allocations` feature.
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+/// Counts the allocations made while `f` runs, `None` if the counting allocator is not installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let (result, allocations) = ALLOCATOR.measure(f);
+        (result, Some(allocations))
+    }
+
+    #[cfg(not(feature = "count-allocations"))]
+    (f(), None)
+}
+
+#[test]
+fn test_counting_allocator() {
+    let allocator = CountingAllocator::new();
+    let small = Layout::from_size_align(100, 8).unwrap();
+    let large = Layout::from_size_align(1000, 8).unwrap();
+
+    let (_, allocations) = allocator.measure(|| unsafe {
+        let a = allocator.alloc(small);
+        let b = allocator.alloc_zeroed(large);
+        allocator.dealloc(b, large);
+        let a = allocator.realloc(a, small, 200);
+        allocator.dealloc(a, Layout::from_size_align(200, 8).unwrap());
+    });
+
+    assert_eq!(
+        allocations,
+        Allocations {
+            count: 3,
+            bytes: 1300,
+            peak: 1100,
+        }
+    );
+    assert_eq!(allocator.live.load(Relaxed), 0);
+
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+}
@@ -0,0 +1,80 @@
+use std::{cell::Cell, iter::Sum, num::NonZeroUsize, time::Duration};
+
+thread_local! {
+    static THREADS: Cell<usize> = const { Cell::new(1) };
+}
+
+/// The number of threads the machine can run in parallel, 1 if it is unknown.
+pub fn available_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// The number of threads `sum` splits its work across on the current thread.
+pub fn threads() -> usize {
+    THREADS.with(Cell::get)
+}
+
+/// Runs `f` with `sum` splitting its work across `threads` threads, 1 solves sequentially. The
+/// worker threads themselves always work sequentially.
+pub fn with_threads<T>(threads: usize, f: impl FnOnce() -> T) -> T {
+    let previous = THREADS.with(|t| t.replace(threads.max(1)));
+    let result = f();
+    THREADS.with(|t| t.set(previous));
+    result
+}
+
+/// Sums `f` over all items. The items are split into one chunk per thread, see `with_threads`.
+pub fn sum<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync) -> R
+where
+    T: Sync,
+    R: Send + Sum,
+{
+    let threads = threads().min(items.len());
+    if threads <= 1 {
+        return items.iter().map(f).sum();
+    }
+
+    let chunk_size = items.len().div_ceil(threads);
+    let f = &f;
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).sum::<R>()))
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .sum()
+    })
+}
+
+/// How many times faster the parallel run was than the sequential one.
+pub fn speedup(sequential: Duration, parallel: Duration) -> f64 {
+    sequential.as_secs_f64() / parallel.as_secs_f64().max(f64::MIN_POSITIVE)
+}
+
+#[test]
+fn test_sum() {
+    let items: Vec<u64> = (1..=1000).collect();
+
+    assert_eq!(threads(), 1);
+    assert_eq!(sum(&items, |&i| i * i), 333_833_500);
+
+    with_threads(4, || {
+        assert_eq!(threads(), 4);
+        assert_eq!(sum(&items, |&i| i * i), 333_833_500);
+        assert_eq!(sum(&items[..3], |&i| i), 6);
+        assert_eq!(sum(&[] as &[u64], |&i| i), 0);
+        assert_eq!(sum(&items, |&i| (i != 500).then_some(i)), None::<u64>);
+    });
+    assert_eq!(threads(), 1);
+
+    let speedup = speedup(Duration::from_millis(9), Duration::from_millis(3));
+    assert!((speedup - 3.0).abs() < 1e-9);
+}
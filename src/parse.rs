@@ -0,0 +1,152 @@
+use std::{fmt::Display, str::FromStr};
+
+/// An error in a puzzle input. Lines and columns are 1-based, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Renders the error together with the offending line of `input` and a marker below the token.
+    pub fn diagnostic(&self, input: &str) -> String {
+        match input.lines().nth(self.line.wrapping_sub(1)) {
+            Some(line) => self.line_diagnostic(line),
+            None => format!("error: {self}\n"),
+        }
+    }
+
+    /// Like `diagnostic`, given only the offending line for inputs that are not kept in memory.
+    pub fn line_diagnostic(&self, line: &str) -> String {
+        let number = self.line.to_string();
+        let indent = " ".repeat(number.len());
+        let offset = " ".repeat(self.column.saturating_sub(1));
+        let marker = "^".repeat(self.token.chars().count().max(1));
+        format!("error: {self}\n{number} | {line}\n{indent} | {offset}{marker}\n")
+    }
+
+    /// Moves an error of a line that was parsed on its own to line `number` of the whole input.
+    pub fn at_line(self, number: usize) -> Self {
+        Self {
+            line: number + self.line - 1,
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+
+        if self.token.is_empty() {
+            Ok(())
+        } else {
+            write!(f, ", found `{}`", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The complete input of a day. Tokens handed to it must be slices of that input, their line and
+/// column are derived from where they lie in it.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self { day, input }
+    }
+
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.input.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= self.input.len())
+            .expect("token must be a slice of the input");
+
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        ParseError {
+            day: self.day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, "expected a non-negative integer"))
+    }
+
+    /// Like `number`, for values that are signed in the code but never negative in the input.
+    pub fn non_negative_i64(&self, token: &str) -> Result<i64, ParseError> {
+        let number: u64 = self.number(token)?;
+        i64::try_from(number)
+            .map_err(|_| self.error(token, format!("expected at most {}", i64::MAX)))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: char,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("expected `{delimiter}`")))
+    }
+
+    /// The part of `s` between `open` and `close`, which must be its first and last character.
+    pub fn delimited(&self, s: &'a str, open: char, close: char) -> Result<&'a str, ParseError> {
+        s.strip_prefix(open)
+            .and_then(|s| s.strip_suffix(close))
+            .ok_or_else(|| self.error(s, format!("expected `{open}...{close}`")))
+    }
+}
+
+#[test]
+fn test_error_position() {
+    let input = "L68\nL30\nRx8\n";
+    let source = Source::new(1, input);
+
+    let error = source.number::<u32>(&input[9..11]).unwrap_err();
+    assert_eq!(error.line, 3);
+    assert_eq!(error.column, 2);
+    assert_eq!(error.token, "x8");
+    assert_eq!(
+        error.to_string(),
+        "day 1, line 3, column 2: expected a non-negative integer, found `x8`"
+    );
+    assert_eq!(
+        error.diagnostic(input),
+        "error: day 1, line 3, column 2: expected a non-negative integer, found `x8`
+3 | Rx8
+  |  ^^
+"
+    );
+
+    assert_eq!(source.non_negative_i64(&input[1..3]), Ok(68));
+    let input = "-1 9223372036854775808";
+    let source = Source::new(8, input);
+    let error = source.non_negative_i64(&input[..2]).unwrap_err();
+    assert_eq!(error.message, "expected a non-negative integer");
+    let error = source.non_negative_i64(&input[3..]).unwrap_err();
+    assert_eq!(error.message, "expected at most 9223372036854775807");
+
+    let input = "L68\nL30\nRx8\n";
+    let source = Source::new(1, input);
+    let error = source.split_once(&input[4..7], ',').unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+}
@@ -0,0 +1,501 @@
+use std::{ops::Range, time::Duration};
+
+use crate::{
+    bench::{DayBench, Stats},
+    memory::{Allocations, format_bytes},
+    runner::{DayResult, PartResult},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "table" => Some(Self::Table),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+pub fn render(results: &[DayResult], format: Format) -> String {
+    match format {
+        Format::Table => table(results),
+        Format::Json => json(results),
+        Format::Csv => csv(results),
+    }
+}
+
+/// Pads the cells of every line to a common column width. Columns in `left_aligned` are aligned
+/// to the left, all others to the right.
+fn align(lines: &[Vec<String>], left_aligned: Range<usize>) -> String {
+    let mut widths = Vec::new();
+    for line in lines {
+        widths.resize(widths.len().max(line.len()), 0);
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+
+    for line in lines {
+        let cells: Vec<_> = line
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(idx, (cell, &width))| {
+                if left_aligned.contains(&idx) {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect();
+        output.push_str(cells.join("  ").trim_end());
+        output.push('\n');
+    }
+
+    output
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{} µs", time.as_micros())
+}
+
+fn format_time_precise(time: Duration) -> String {
+    format!("{:.2} µs", time.as_nanos() as f64 / 1000.0)
+}
+
+fn format_answer(part: &Option<PartResult>) -> String {
+    part.as_ref().map_or("-".to_string(), |p| p.answer.clone())
+}
+
+fn format_part_time(part: &Option<PartResult>) -> String {
+    part.as_ref()
+        .map_or("-".to_string(), |p| format_time(p.time))
+}
+
+/// Human readable table with one row per day, answers left aligned and timings right aligned.
+pub fn table(results: &[DayResult]) -> String {
+    let mut lines = vec![vec![
+        "Day".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Parsing".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Total".to_string(),
+    ]];
+
+    for result in results {
+        lines.push(vec![
+            result.day.to_string(),
+            format_answer(&result.part1),
+            format_answer(&result.part2),
+            format_time(result.parse_time),
+            format_part_time(&result.part1),
+            format_part_time(&result.part2),
+            format_time(result.total_time()),
+        ]);
+    }
+
+    let mut output = align(&lines, 1..3);
+    let total = results.iter().map(DayResult::total_time).sum();
+    output.push_str(&format!("Total: {}\n", format_time(total)));
+
+    if results.iter().any(|r| r.parse_allocations.is_some()) {
+        output.push('\n');
+        output.push_str(&allocations_table(results));
+    }
+
+    output
+}
+
+/// One row per day and phase, only filled if allocations are counted.
+fn allocations_table(results: &[DayResult]) -> String {
+    let mut lines = vec![
+        ["Day", "Phase", "Allocations", "Allocated", "Peak"]
+            .map(String::from)
+            .to_vec(),
+    ];
+
+    for result in results {
+        for (phase, allocations) in result.allocations() {
+            lines.push(vec![
+                result.day.to_string(),
+                phase.to_string(),
+                allocations.count.to_string(),
+                format_bytes(allocations.bytes),
+                format_bytes(allocations.peak),
+            ]);
+        }
+    }
+
+    align(&lines, 1..2)
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_answer(part: &Option<PartResult>) -> String {
+    part.as_ref()
+        .map_or("null".to_string(), |p| json_string(&p.answer))
+}
+
+fn json_nanos(part: &Option<PartResult>) -> String {
+    part.as_ref()
+        .map_or("null".to_string(), |p| p.time.as_nanos().to_string())
+}
+
+fn json_allocations(allocations: &Allocations) -> String {
+    format!(
+        "{{\"count\": {}, \"bytes\": {}, \"peak_bytes\": {}}}",
+        allocations.count, allocations.bytes, allocations.peak
+    )
+}
+
+/// Answers are strings so that consumers don't lose precision on large numbers, durations are
+/// nanoseconds and `null` marks parts that were not run. If allocations are counted, they are
+/// listed for every phase that was run.
+pub fn json(results: &[DayResult]) -> String {
+    let days: Vec<_> = results
+        .iter()
+        .map(|result| {
+            let allocations: Vec<_> = result
+                .allocations()
+                .iter()
+                .map(|(phase, allocations)| {
+                    format!("\"{phase}\": {}", json_allocations(allocations))
+                })
+                .collect();
+            let allocations = if allocations.is_empty() {
+                String::new()
+            } else {
+                format!(", \"allocations\": {{{}}}", allocations.join(", "))
+            };
+
+            format!(
+                concat!(
+                    "    {{\"day\": {}, ",
+                    "\"answers\": {{\"part1\": {}, \"part2\": {}}}, ",
+                    "\"durations_ns\": {{\"parse\": {}, \"part1\": {}, \"part2\": {}, \"total\": {}}}{}}}"
+                ),
+                result.day,
+                json_answer(&result.part1),
+                json_answer(&result.part2),
+                result.parse_time.as_nanos(),
+                json_nanos(&result.part1),
+                json_nanos(&result.part2),
+                result.total_time().as_nanos(),
+                allocations,
+            )
+        })
+        .collect();
+
+    let total: Duration = results.iter().map(DayResult::total_time).sum();
+
+    format!(
+        "{{\n  \"days\": [\n{}\n  ],\n  \"total_ns\": {}\n}}\n",
+        days.join(",\n"),
+        total.as_nanos()
+    )
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn csv_answer(part: &Option<PartResult>) -> String {
+    part.as_ref()
+        .map_or(String::new(), |p| csv_field(&p.answer))
+}
+
+fn csv_nanos(part: &Option<PartResult>) -> String {
+    part.as_ref()
+        .map_or(String::new(), |p| p.time.as_nanos().to_string())
+}
+
+fn csv_allocations(allocations: Option<Allocations>) -> String {
+    allocations.map_or(",,".to_string(), |a| {
+        format!("{},{},{}", a.count, a.bytes, a.peak)
+    })
+}
+
+/// One row per day, empty cells mark parts that were not run. If allocations are counted, the
+/// count, bytes and peak bytes of every phase are appended.
+pub fn csv(results: &[DayResult]) -> String {
+    let with_allocations = results.iter().any(|r| r.parse_allocations.is_some());
+
+    let mut output = String::from("day,part1,part2,parse_ns,part1_ns,part2_ns,total_ns");
+    if with_allocations {
+        for phase in ["parse", "part1", "part2"] {
+            output.push_str(&format!(
+                ",{phase}_allocations,{phase}_bytes,{phase}_peak_bytes"
+            ));
+        }
+    }
+    output.push('\n');
+
+    for result in results {
+        output.push_str(&format!(
+            "{},{},{},{},{},{},{}",
+            result.day,
+            csv_answer(&result.part1),
+            csv_answer(&result.part2),
+            result.parse_time.as_nanos(),
+            csv_nanos(&result.part1),
+            csv_nanos(&result.part2),
+            result.total_time().as_nanos(),
+        ));
+        if with_allocations {
+            for allocations in [
+                result.parse_allocations,
+                result.part1.as_ref().and_then(|p| p.allocations),
+                result.part2.as_ref().and_then(|p| p.allocations),
+            ] {
+                output.push(',');
+                output.push_str(&csv_allocations(allocations));
+            }
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+pub fn render_bench(benches: &[DayBench], format: Format) -> String {
+    match format {
+        Format::Table => bench_table(benches),
+        Format::Json => bench_json(benches),
+        Format::Csv => bench_csv(benches),
+    }
+}
+
+/// One row per day and phase.
+pub fn bench_table(benches: &[DayBench]) -> String {
+    let mut lines = vec![
+        [
+            "Day", "Phase", "Runs", "Min", "Median", "p95", "Mean", "Stddev",
+        ]
+        .map(String::from)
+        .to_vec(),
+    ];
+
+    for bench in benches {
+        for (phase, stats) in bench.phases() {
+            lines.push(vec![
+                bench.day.to_string(),
+                phase.to_string(),
+                bench.runs.to_string(),
+                format_time_precise(stats.min),
+                format_time_precise(stats.median),
+                format_time_precise(stats.p95),
+                format_time_precise(stats.mean),
+                format_time_precise(stats.stddev),
+            ]);
+        }
+    }
+
+    align(&lines, 1..2)
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        "{{\"min\": {}, \"median\": {}, \"p95\": {}, \"mean\": {}, \"stddev\": {}}}",
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.stddev.as_nanos()
+    )
+}
+
+/// Statistics are nanoseconds, phases that were not run are left out.
+pub fn bench_json(benches: &[DayBench]) -> String {
+    let days: Vec<_> = benches
+        .iter()
+        .map(|bench| {
+            let phases: Vec<_> = bench
+                .phases()
+                .into_iter()
+                .map(|(phase, stats)| format!("\"{phase}\": {}", json_stats(stats)))
+                .collect();
+
+            format!(
+                "    {{\"day\": {}, \"runs\": {}, \"phases_ns\": {{{}}}}}",
+                bench.day,
+                bench.runs,
+                phases.join(", ")
+            )
+        })
+        .collect();
+
+    format!("{{\n  \"days\": [\n{}\n  ]\n}}\n", days.join(",\n"))
+}
+
+/// One row per day and phase.
+pub fn bench_csv(benches: &[DayBench]) -> String {
+    let mut output = String::from("day,phase,runs,min_ns,median_ns,p95_ns,mean_ns,stddev_ns\n");
+
+    for bench in benches {
+        for (phase, stats) in bench.phases() {
+            output.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                bench.day,
+                phase,
+                bench.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            ));
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+fn example_results() -> Vec<DayResult> {
+    vec![
+        DayResult {
+            day: 1,
+            parse_time: Duration::from_nanos(1500),
+            parse_allocations: None,
+            part1: Some(PartResult {
+                answer: "3".to_string(),
+                has_solution: true,
+                time: Duration::from_nanos(200),
+                allocations: None,
+            }),
+            part2: Some(PartResult {
+                answer: "6".to_string(),
+                has_solution: true,
+                time: Duration::from_nanos(300),
+                allocations: None,
+            }),
+        },
+        DayResult {
+            day: 9,
+            parse_time: Duration::from_nanos(100),
+            parse_allocations: None,
+            part1: Some(PartResult {
+                answer: "50".to_string(),
+                has_solution: true,
+                time: Duration::from_nanos(10),
+                allocations: None,
+            }),
+            part2: None,
+        },
+    ]
+}
+
+#[test]
+fn test_json() {
+    assert_eq!(
+        json(&example_results()),
+        r#"{
+  "days": [
+    {"day": 1, "answers": {"part1": "3", "part2": "6"}, "durations_ns": {"parse": 1500, "part1": 200, "part2": 300, "total": 2000}},
+    {"day": 9, "answers": {"part1": "50", "part2": null}, "durations_ns": {"parse": 100, "part1": 10, "part2": null, "total": 110}}
+  ],
+  "total_ns": 2110
+}
+"#
+    );
+    assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\n""#);
+}
+
+#[test]
+fn test_csv() {
+    assert_eq!(
+        csv(&example_results()),
+        "day,part1,part2,parse_ns,part1_ns,part2_ns,total_ns
+1,3,6,1500,200,300,2000
+9,50,,100,10,,110
+"
+    );
+    assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+}
+
+#[test]
+fn test_allocations() {
+    let mut result = example_results().remove(1);
+    result.parse_allocations = Some(Allocations {
+        count: 3,
+        bytes: 2048,
+        peak: 1024,
+    });
+    result.part1.as_mut().unwrap().allocations = Some(Allocations::default());
+
+    assert_eq!(
+        csv(std::slice::from_ref(&result)),
+        "day,part1,part2,parse_ns,part1_ns,part2_ns,total_ns,\
+parse_allocations,parse_bytes,parse_peak_bytes,\
+part1_allocations,part1_bytes,part1_peak_bytes,\
+part2_allocations,part2_bytes,part2_peak_bytes
+9,50,,100,10,,110,3,2048,1024,0,0,0,,,
+"
+    );
+    assert!(json(std::slice::from_ref(&result)).contains(
+        r#""allocations": {"parse": {"count": 3, "bytes": 2048, "peak_bytes": 1024}, "part1": {"count": 0, "bytes": 0, "peak_bytes": 0}}}"#
+    ));
+    assert!(table(&[result]).ends_with(
+        "Day  Phase  Allocations  Allocated     Peak
+  9  parse            3    2.0 KiB  1.0 KiB
+  9  part1            0        0 B      0 B
+"
+    ));
+}
+
+#[test]
+fn test_bench_csv() {
+    let stats = |n| Stats {
+        min: Duration::from_nanos(n),
+        median: Duration::from_nanos(n + 1),
+        p95: Duration::from_nanos(n + 2),
+        mean: Duration::from_nanos(n + 1),
+        stddev: Duration::from_nanos(1),
+    };
+    let bench = DayBench {
+        day: 9,
+        runs: 10,
+        parse: stats(100),
+        part1: Some(stats(10)),
+        part2: None,
+        total: stats(110),
+    };
+
+    assert_eq!(
+        bench_csv(&[bench]),
+        "day,phase,runs,min_ns,median_ns,p95_ns,mean_ns,stddev_ns
+9,parse,10,100,101,102,101,1
+9,part1,10,10,11,12,11,1
+9,total,10,110,111,112,111,1
+"
+    );
+}
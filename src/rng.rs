@@ -0,0 +1,81 @@
+use std::ops::RangeInclusive;
+
+/// A small and fast pseudo random number generator (xorshift64*). It is not suitable for anything
+/// secret, but the same seed always gives the same numbers on every platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // splitmix64 of the seed, so that similar seeds give unrelated numbers
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        // xorshift never leaves the state 0
+        Self { state: z.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `range`. Very large ranges are slightly biased towards their start.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "the range must not be empty");
+
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index below `len`, which must not be 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// True with the given probability in percent.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(0..=99) < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let numbers = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..100).map(|_| rng.range(1..=6)).collect::<Vec<_>>()
+    };
+    assert_eq!(numbers(0), numbers(0));
+    assert_ne!(numbers(0), numbers(1));
+    assert!(numbers(2).iter().all(|n| (1..=6).contains(n)));
+    assert!((1..=6).all(|n| numbers(3).contains(&n)));
+
+    let mut rng = Rng::new(4);
+    assert_eq!(rng.range(7..=7), 7);
+    assert_ne!(rng.range(0..=u64::MAX), rng.range(0..=u64::MAX));
+
+    let mut items: Vec<_> = (0..20).collect();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..20).collect::<Vec<_>>());
+    items.sort_unstable();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
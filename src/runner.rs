@@ -0,0 +1,534 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Answer, ParseError, Solution,
+    answers::Verdict,
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    fixtures::{examples, fixtures_dir},
+    memory::{self, Allocations},
+    parallel,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+/// How a day is solved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Solve only this part instead of both.
+    pub part: Option<Part>,
+    /// The input is one of the puzzle examples, see `Solution::parse_example`.
+    pub example: bool,
+    /// Split independent items of the input across all cores, see `parallel::sum`.
+    pub parallel: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub answer: String,
+    /// `false` if the input has no solution, see `Answer::has_solution`.
+    pub has_solution: bool,
+    pub time: Duration,
+    /// `None` unless allocations are counted, see `memory::measure`.
+    pub allocations: Option<Allocations>,
+}
+
+/// Answers and timings of a single day. A part is `None` if it was not selected or is unsolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parse_allocations: Option<Allocations>,
+    pub part1: Option<PartResult>,
+    pub part2: Option<PartResult>,
+}
+
+impl DayResult {
+    pub fn part(&self, part: Part) -> Option<&PartResult> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    /// The parts that were solved.
+    pub fn parts(&self) -> impl Iterator<Item = (Part, &PartResult)> {
+        [(Part::One, &self.part1), (Part::Two, &self.part2)]
+            .into_iter()
+            .filter_map(|(part, result)| result.as_ref().map(|r| (part, r)))
+    }
+
+    /// The durations of all phases that were run: `parse`, `part1`, `part2` and `total`.
+    pub fn phases(&self) -> Vec<(&'static str, Duration)> {
+        let mut phases = vec![("parse", self.parse_time)];
+        phases.extend(self.part1.as_ref().map(|p| ("part1", p.time)));
+        phases.extend(self.part2.as_ref().map(|p| ("part2", p.time)));
+        phases.push(("total", self.total_time()));
+        phases
+    }
+
+    /// The allocations of all phases that were run, empty unless allocations are counted.
+    pub fn allocations(&self) -> Vec<(&'static str, Allocations)> {
+        let mut allocations = Vec::new();
+        allocations.extend(self.parse_allocations.map(|a| ("parse", a)));
+        allocations.extend(
+            self.part1
+                .as_ref()
+                .and_then(|p| Some(("part1", p.allocations?))),
+        );
+        allocations.extend(
+            self.part2
+                .as_ref()
+                .and_then(|p| Some(("part2", p.allocations?))),
+        );
+        allocations
+    }
+
+    /// False if any part found that the input has no solution.
+    pub fn has_solutions(&self) -> bool {
+        self.parts()
+            .all(|(_, part_result)| part_result.has_solution)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time
+            + self.part1.as_ref().map_or(Duration::ZERO, |p| p.time)
+            + self.part2.as_ref().map_or(Duration::ZERO, |p| p.time)
+    }
+}
+
+pub type Solver = fn(&str, &Config) -> Result<DayResult, ParseError>;
+
+pub const DAYS: [Solver; 12] = [
+    solve::<Day01>,
+    solve::<Day02>,
+    solve::<Day03>,
+    solve::<Day04>,
+    solve::<Day05>,
+    solve::<Day06>,
+    solve::<Day07>,
+    solve::<Day08>,
+    solve::<Day09>,
+    solve::<Day10>,
+    solve::<Day11>,
+    solve::<Day12>,
+];
+
+pub fn solver(day: u8) -> Option<Solver> {
+    DAYS.get(usize::from(day).checked_sub(1)?).copied()
+}
+
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day:02}.txt"))
+}
+
+/// Runs `f` and measures its duration and, if enabled, its allocations.
+fn measured<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<Allocations>) {
+    let ((result, time), allocations) = memory::measure(|| {
+        let before = Instant::now();
+        let result = f();
+        (result, before.elapsed())
+    });
+    (result, time, allocations)
+}
+
+fn part_result(
+    (answer, time, allocations): (impl Answer, Duration, Option<Allocations>),
+) -> Option<PartResult> {
+    answer.is_solved().then(|| PartResult {
+        answer: answer.to_string(),
+        has_solution: answer.has_solution(),
+        time,
+        allocations,
+    })
+}
+
+/// Parses the input of a day, binaries can replace `default_parser` to configure a day.
+pub type Parser<'a, S> = &'a dyn Fn(&str, &Config) -> Result<<S as Solution>::Input, ParseError>;
+
+/// `Solution::parse`, or `Solution::parse_example` for the examples.
+pub fn default_parser<S: Solution>(input: &str, config: &Config) -> Result<S::Input, ParseError> {
+    if config.example {
+        S::parse_example(input)
+    } else {
+        S::parse(input)
+    }
+}
+
+/// Parses the input and solves the configured parts.
+pub fn solve<S: Solution>(input: &str, config: &Config) -> Result<DayResult, ParseError> {
+    solve_with::<S>(input, config, &default_parser::<S>)
+}
+
+/// Like `solve`, but parses the input with `parse`.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    config: &Config,
+    parse: Parser<S>,
+) -> Result<DayResult, ParseError> {
+    let threads = if config.parallel {
+        parallel::available_threads()
+    } else {
+        1
+    };
+    parallel::with_threads(threads, || solve_with_threads::<S>(input, config, parse))
+}
+
+fn solve_with_threads<S: Solution>(
+    input: &str,
+    config: &Config,
+    parse: Parser<S>,
+) -> Result<DayResult, ParseError> {
+    let (parsed_input, parse_time, parse_allocations) = measured(|| parse(input, config));
+    let parsed_input = parsed_input?;
+
+    let part1 = (config.part != Some(Part::Two))
+        .then(|| measured(|| S::part1(&parsed_input)))
+        .and_then(part_result);
+    let part2 = (config.part != Some(Part::One))
+        .then(|| measured(|| S::part2(&parsed_input)))
+        .and_then(part_result);
+
+    Ok(DayResult {
+        day: S::DAY,
+        parse_time,
+        parse_allocations,
+        part1,
+        part2,
+    })
+}
+
+fn print_result(result: &DayResult) {
+    for (part, part_result) in result.parts() {
+        println!("Part {}: {}", part.number(), part_result.answer);
+        if !part_result.has_solution {
+            eprintln!(
+                "error: part {} has no solution for this input",
+                part.number()
+            );
+        }
+    }
+
+    let allocations =
+        |allocations: Option<Allocations>| allocations.map_or(String::new(), |a| format!(" ({a})"));
+
+    println!("==========================================");
+    println!(
+        "Parsing: {} µs{}",
+        result.parse_time.as_micros(),
+        allocations(result.parse_allocations)
+    );
+    for (part, part_result) in result.parts() {
+        println!(
+            "Part {} : {} µs{}",
+            part.number(),
+            part_result.time.as_micros(),
+            allocations(part_result.allocations)
+        );
+    }
+    println!("Total  : {} µs", result.total_time().as_micros());
+}
+
+/// Solves the input and prints the result. In parallel mode it is solved sequentially as well to
+/// print the speedup.
+fn solve_and_print<S: Solution>(
+    input: &str,
+    config: &Config,
+    parse: Parser<S>,
+) -> Result<DayResult, ParseError> {
+    let result = solve_with::<S>(input, config, parse)?;
+    print_result(&result);
+
+    if config.parallel {
+        let sequential = solve_with::<S>(
+            input,
+            &Config {
+                parallel: false,
+                ..*config
+            },
+            parse,
+        )?;
+        println!(
+            "Speedup: {:.2}x on {} threads, {} µs sequentially",
+            parallel::speedup(sequential.total_time(), result.total_time()),
+            parallel::available_threads(),
+            sequential.total_time().as_micros()
+        );
+    }
+
+    Ok(result)
+}
+
+/// Solves every example fixture of the day and compares the answers with the expected ones.
+fn run_examples<S: Solution>(parallel: bool, parse: Parser<S>) -> ExitCode {
+    let examples = match examples(&fixtures_dir(), Some(S::DAY)) {
+        Ok(examples) => examples,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for example in examples {
+        println!("Example {}", example.path.display());
+
+        let input = match std::fs::read_to_string(&example.path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("error: cannot read {}: {error}", example.path.display());
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let config = Config {
+            parallel,
+            ..example.config()
+        };
+        let result = match solve_and_print::<S>(&input, &config, parse) {
+            Ok(result) if !result.has_solutions() => {
+                exit_code = ExitCode::FAILURE;
+                result
+            }
+            Ok(result) => result,
+            Err(error) => {
+                eprint!("{}", error.diagnostic(&input));
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for check in example.check(&result, config.part) {
+            match check.verdict {
+                Verdict::Wrong { expected } => eprintln!(
+                    "Part {}: WRONG answer {}, expected {expected}",
+                    check.part.number(),
+                    check.answer
+                ),
+                Verdict::Missing { expected } => {
+                    eprintln!(
+                        "Part {}: no answer, expected {expected}",
+                        check.part.number()
+                    )
+                }
+                Verdict::Correct | Verdict::Unknown => continue,
+            }
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    exit_code
+}
+
+/// The inputs at `path`: the file itself, or all files in the directory sorted by name. Hidden
+/// files are skipped.
+pub fn input_files(path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if !hidden && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
+
+/// Solves every input file at the given paths, printing the results of each one.
+fn run_files<S: Solution>(paths: &[PathBuf], config: &Config, parse: Parser<S>) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for path in paths {
+        let files = match input_files(path) {
+            Ok(files) if files.is_empty() => {
+                eprintln!("error: no inputs in {}", path.display());
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+            Ok(files) => files,
+            Err(error) => {
+                eprintln!("error: cannot read {}: {error}", path.display());
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for file in files {
+            println!("Input {}", file.display());
+
+            let input = match std::fs::read_to_string(&file) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("error: cannot read {}: {error}", file.display());
+                    exit_code = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            match solve_and_print::<S>(&input, config, parse) {
+                Ok(result) if result.has_solutions() => {}
+                Ok(_) => exit_code = ExitCode::FAILURE,
+                Err(error) => {
+                    eprint!("{}: {}", file.display(), error.diagnostic(&input));
+                    exit_code = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Solves both parts of the inputs given as arguments and prints answers and timings. Arguments
+/// are input files or directories of them, without any the input is read from stdin. With
+/// `--example` the day's example fixtures are solved instead, `--parallel` solves them in parallel
+/// and prints the speedup.
+pub fn run<S: Solution>() -> ExitCode {
+    run_with::<S>(std::env::args().skip(1), "", &default_parser::<S>)
+}
+
+/// Like `run`, for binaries with options of their own. `args` are the arguments they did not
+/// handle, `help` describes their options and `parse` parses the inputs with them.
+pub fn run_with<S: Solution>(
+    args: impl IntoIterator<Item = String>,
+    help: &str,
+    parse: Parser<S>,
+) -> ExitCode {
+    let mut usage = format!(
+        "usage: day{:02} [--example] [--parallel] [PATH...]\n\
+         PATH is an input file or a directory of input files, stdin is read if none is given.\n\
+         --parallel splits the work across all cores and prints the speedup over solving sequentially.",
+        S::DAY
+    );
+    if !help.is_empty() {
+        usage = format!("{usage}\n{help}");
+    }
+
+    let mut example = false;
+    let mut parallel = false;
+    let mut paths = Vec::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "--example" => example = true,
+            "--parallel" | "-j" => parallel = true,
+            "--help" | "-h" => {
+                eprintln!("{usage}");
+                return ExitCode::FAILURE;
+            }
+            _ if arg.starts_with('-') => {
+                eprintln!("unknown argument `{arg}`\n{usage}");
+                return ExitCode::FAILURE;
+            }
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+
+    if example {
+        if !paths.is_empty() {
+            eprintln!("--example cannot be combined with input paths\n{usage}");
+            return ExitCode::FAILURE;
+        }
+        return run_examples::<S>(parallel, parse);
+    }
+
+    let config = Config {
+        parallel,
+        ..Config::default()
+    };
+
+    if !paths.is_empty() {
+        return run_files::<S>(&paths, &config, parse);
+    }
+
+    let mut input = String::new();
+    if let Err(error) = std::io::stdin().read_to_string(&mut input) {
+        eprintln!("error: cannot read input: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    match solve_and_print::<S>(&input, &config, parse) {
+        Ok(result) if result.has_solutions() => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(error) => {
+            eprint!("{}", error.diagnostic(&input));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[test]
+fn test_input_files() {
+    let dir = fixtures_dir();
+    let files = input_files(&dir).unwrap();
+    assert!(files.is_sorted());
+    assert!(files.contains(&dir.join("day01.txt")));
+    assert!(files.contains(&dir.join("expected.txt")));
+
+    let file = dir.join("day02.txt");
+    assert_eq!(input_files(&file).unwrap(), vec![file]);
+}
+
+#[test]
+fn test_no_solution() {
+    // the light can never be turned on, the second part of day 10 is unsolved
+    let input = "[##] (0) (0)\n";
+    let result = solve::<Day10>(input, &Config::default()).unwrap();
+    let part1 = result.part1.as_ref().unwrap();
+    assert_eq!(part1.answer, "no solution");
+    assert!(!part1.has_solution);
+    assert!(result.part2.is_none());
+    assert!(!result.has_solutions());
+    assert_eq!(result.phases().len(), 3);
+
+    let path = std::env::temp_dir().join(format!("no-solution-{}.txt", std::process::id()));
+    std::fs::write(&path, input).unwrap();
+    let exit_code = run_files::<Day10>(
+        std::slice::from_ref(&path),
+        &Config::default(),
+        &default_parser::<Day10>,
+    );
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(exit_code, ExitCode::FAILURE);
+
+    let result = solve::<Day01>(include_str!("../fixtures/day01.txt"), &Config::default()).unwrap();
+    assert!(result.has_solutions());
+}
@@ -0,0 +1,220 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, hash_map::Entry},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The states from the start to a goal, both included, and the cost of the steps between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchResult<S, C> {
+    Found(Path<S, C>),
+    /// No goal can be reached, all `explored` states reachable from the start were visited.
+    Unreachable {
+        explored: usize,
+    },
+}
+
+impl<S, C> SearchResult<S, C> {
+    pub fn found(self) -> Option<Path<S, C>> {
+        match self {
+            Self::Found(path) => Some(path),
+            Self::Unreachable { .. } => None,
+        }
+    }
+
+    pub fn cost(self) -> Option<C> {
+        self.found().map(|path| path.cost)
+    }
+}
+
+/// Every visited state with the index of the one it was reached from.
+struct Visited<S> {
+    states: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone> Visited<S> {
+    fn path<C>(&self, mut idx: usize, cost: C) -> Path<S, C> {
+        let mut states = vec![self.states[idx].0.clone()];
+        while let Some(parent) = self.states[idx].1 {
+            states.push(self.states[parent].0.clone());
+            idx = parent;
+        }
+        states.reverse();
+
+        Path { states, cost }
+    }
+}
+
+/// Breadth-first search for a path with the fewest steps from `start` to a state that satisfies
+/// `is_goal`. Every state is visited at most once.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited {
+        states: vec![(start.clone(), None)],
+    };
+    let mut depths = HashMap::from([(start, 0)]);
+
+    // the visited states are in the order they are found, which is the queue of the search
+    let mut idx = 0;
+    while let Some((state, _)) = visited.states.get(idx) {
+        let state = state.clone();
+        let depth = depths[&state];
+        if is_goal(&state) {
+            return SearchResult::Found(visited.path(idx, depth));
+        }
+
+        for successor in successors(&state) {
+            if let Entry::Vacant(entry) = depths.entry(successor.clone()) {
+                entry.insert(depth + 1);
+                visited.states.push((successor, Some(idx)));
+            }
+        }
+        idx += 1;
+    }
+
+    SearchResult::Unreachable {
+        explored: visited.states.len(),
+    }
+}
+
+/// Dijkstra's search for a cheapest path from `start` to a state that satisfies `is_goal`.
+/// `successors` returns the next states together with the cost of getting there, which must not
+/// be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search for a cheapest path from `start` to a state that satisfies `is_goal`. `heuristic`
+/// estimates the remaining cost of a state and must never overestimate it, and must not decrease
+/// by more than the cost of a step, otherwise the path may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited {
+        states: vec![(start.clone(), None)],
+    };
+    // index in `visited` and cheapest known cost of every state, `None` once it is closed
+    let mut costs = HashMap::from([(start.clone(), (0, Some(C::default())))]);
+    let mut open = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, idx))) = open.pop() {
+        let state = visited.states[idx].0.clone();
+        let entry = costs.get_mut(&state).expect("visited states have a cost");
+        if entry.1 != Some(cost) {
+            // closed, or reached more cheaply since this was queued
+            continue;
+        }
+        entry.1 = None;
+
+        if is_goal(&state) {
+            return SearchResult::Found(visited.path(idx, cost));
+        }
+
+        for (successor, step_cost) in successors(&state) {
+            let successor_cost = cost + step_cost;
+            let successor_idx = match costs.get_mut(&successor) {
+                Some((_, None)) => continue,
+                Some((_, Some(known))) if *known <= successor_cost => continue,
+                Some((successor_idx, known)) => {
+                    *known = Some(successor_cost);
+                    visited.states[*successor_idx].1 = Some(idx);
+                    *successor_idx
+                }
+                None => {
+                    let successor_idx = visited.states.len();
+                    visited.states.push((successor.clone(), Some(idx)));
+                    costs.insert(successor.clone(), (successor_idx, Some(successor_cost)));
+                    successor_idx
+                }
+            };
+
+            let estimate = successor_cost + heuristic(&successor);
+            open.push(Reverse((estimate, successor_cost, successor_idx)));
+        }
+    }
+
+    SearchResult::Unreachable {
+        explored: visited.states.len(),
+    }
+}
+
+#[test]
+fn test_search() {
+    // a 5x5 grid with walls, moving right costs 1, all other moves 3
+    let walls = ["..#..", "..#..", "..#..", ".....", "#####"];
+    let is_free = |(x, y): (i32, i32)| {
+        (0..5).contains(&x)
+            && (0..5).contains(&y)
+            && walls[y as usize].as_bytes()[x as usize] == b'.'
+    };
+    let steps = move |&(x, y): &(i32, i32)| {
+        [
+            ((x + 1, y), 1),
+            ((x - 1, y), 3),
+            ((x, y + 1), 3),
+            ((x, y - 1), 3),
+        ]
+        .into_iter()
+        .filter(move |&(position, _)| is_free(position))
+    };
+    let goal = (4, 0);
+
+    let path = bfs((0, 0), |s| steps(s).map(|(p, _)| p), |&s| s == goal)
+        .found()
+        .unwrap();
+    assert_eq!(path.cost, 10);
+    assert_eq!(path.states.len(), 11);
+    assert_eq!(path.states[0], (0, 0));
+    assert_eq!(path.states[10], goal);
+
+    let cheapest = dijkstra((0, 0), steps, |&s| s == goal).found().unwrap();
+    assert_eq!(cheapest.cost, 4 + 3 * 6);
+    assert!(
+        cheapest
+            .states
+            .windows(2)
+            .all(|w| steps(&w[0]).any(|(p, _)| p == w[1]))
+    );
+
+    let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+    let path = astar((0, 0), steps, manhattan, |&s| s == goal);
+    assert_eq!(path.cost(), Some(cheapest.cost));
+
+    assert_eq!(
+        bfs((0, 0), |s| steps(s).map(|(p, _)| p), |&s| s == (0, 4)),
+        SearchResult::Unreachable { explored: 17 }
+    );
+    assert_eq!(dijkstra((0, 0), steps, |&s| s == (0, 4)).cost(), None);
+    assert_eq!(bfs(7, |_| [], |&s| s == 7).cost(), Some(0));
+}
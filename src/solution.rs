@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// One day of the calendar: parse the puzzle input once, then solve both parts on it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses one of the puzzle examples. Override this if the examples use different parameters
+    /// than the real puzzle.
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// The result of a part. Everything that can be printed is an answer, `Unsolved` marks a part
+/// that has no solution yet.
+pub trait Answer: Display {
+    fn is_solved(&self) -> bool {
+        true
+    }
+
+    /// False if the part is solved, but the input has no solution, see `Maybe`.
+    fn has_solution(&self) -> bool {
+        true
+    }
+}
+
+impl Answer for i64 {}
+impl Answer for i128 {}
+impl Answer for u64 {}
+impl Answer for u128 {}
+impl Answer for usize {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+impl Answer for Unsolved {
+    fn is_solved(&self) -> bool {
+        false
+    }
+}
+
+/// The answer of a part that has no solution for some inputs, e.g. because a target cannot be
+/// reached. `None` is reported as "no solution", unlike `Unsolved` which is not reported at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Maybe<T>(pub Option<T>);
+
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => write!(f, "no solution"),
+        }
+    }
+}
+
+impl<T: Answer> Answer for Maybe<T> {
+    fn is_solved(&self) -> bool {
+        self.0.as_ref().is_none_or(Answer::is_solved)
+    }
+
+    fn has_solution(&self) -> bool {
+        self.0.as_ref().is_some_and(Answer::has_solution)
+    }
+}
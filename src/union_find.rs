@@ -0,0 +1,114 @@
+/// Disjoint sets of the elements `0..len` with path compression and union by size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    nof_components: usize,
+}
+
+impl UnionFind {
+    /// Every element starts in its own component.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            nof_components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The representative of the component containing `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+
+        root
+    }
+
+    /// Merges the components of `a` and `b`, returns `false` if they already were the same.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.sizes[a] >= self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.nof_components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The size of the component containing `element`.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    pub fn nof_components(&self) -> usize {
+        self.nof_components
+    }
+
+    /// The sizes of all components in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|&(element, &parent)| element == parent)
+            .map(|(root, _)| self.sizes[root])
+            .collect()
+    }
+
+    /// The sizes of the `k` largest components, largest first. Fewer if there are less components.
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes = self.component_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+}
+
+#[test]
+fn test_union_find() {
+    let mut sets = UnionFind::new(6);
+    assert_eq!(sets.nof_components(), 6);
+
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 1));
+    assert!(sets.union(3, 4));
+    assert!(!sets.union(0, 2));
+
+    assert!(sets.connected(0, 2));
+    assert!(!sets.connected(0, 3));
+    assert_eq!(sets.size(2), 3);
+    assert_eq!(sets.nof_components(), 3);
+    assert_eq!(sets.largest(2), [3, 2]);
+    assert_eq!(sets.largest(10), [3, 2, 1]);
+
+    let mut sizes = sets.component_sizes();
+    sizes.sort_unstable();
+    assert_eq!(sizes, [1, 2, 3]);
+}